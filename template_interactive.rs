@@ -15,8 +15,9 @@ const MOD: i64 = 998244353_i64;
 const LOWERCASE: &str = "abcdefghijklmnopqrstuvwxyz";
 const UPPERCASE: &str = "ABCDEFGHIJKLMNOPQRSTUVWXYZ";
 const DIGITS: &str = "0123456789";
-//ROLLING_HASH_MOD: i64 = 2305843009213693951_i64;
-const ROLLING_HASH_MOD: i64 = 8128812800000059_i64;
+// rolling_hash_mul は 2^61 - 1 専用の剰余計算をしているため変更しないこと
+const ROLLING_HASH_MOD: i64 = 2305843009213693951_i64;
+const _: () = assert!(ROLLING_HASH_MOD == (1 << 61) - 1);
 const TAKAHASHI: &str = "Takahashi";
 const AOKI: &str = "Aoki";
 
@@ -71,6 +72,110 @@ macro_rules! chmax {
     }};
 }
 
+// 浮動小数点数の最小値・最大値。`f64::min` / `f64::max` と同様に NaN は無視する（全て NaN の場合のみ NaN）。
+macro_rules! fmin {
+    ($a:expr $(,)*) => {{
+        $a
+    }};
+    ($a:expr, $($rest:expr),+ $(,)*) => {{
+        f64::min($a, fmin!($($rest),+))
+    }};
+}
+
+macro_rules! fmax {
+    ($a:expr $(,)*) => {{
+        $a
+    }};
+    ($a:expr, $($rest:expr),+ $(,)*) => {{
+        f64::max($a, fmax!($($rest),+))
+    }};
+}
+
+// 浮動小数点数版の chmin! / chmax! 。NaN の候補は無視し、`$base` が NaN の場合は NaN でない候補で必ず更新する。
+macro_rules! chfmin {
+    ($base:expr, $($cmps:expr),+ $(,)*) => {{
+        let cmp_min: f64 = fmin!($($cmps),+);
+        if !cmp_min.is_nan() && (f64::is_nan($base) || $base > cmp_min) {
+            $base = cmp_min;
+            true
+        } else {
+            false
+        }
+    }};
+}
+
+macro_rules! chfmax {
+    ($base:expr, $($cmps:expr),+ $(,)*) => {{
+        let cmp_max: f64 = fmax!($($cmps),+);
+        if !cmp_max.is_nan() && (f64::is_nan($base) || $base < cmp_max) {
+            $base = cmp_max;
+            true
+        } else {
+            false
+        }
+    }};
+}
+
+// `$key($cmp) < $key($base)` のとき `$base` を `$cmp` で更新する。
+// 使用例: chmin_by_key!(best, (x, y), |p: &(i64, i64)| p.0 * p.0 + p.1 * p.1);
+macro_rules! chmin_by_key {
+    ($base:expr, $cmp:expr, $key:expr $(,)*) => {{
+        let cmp = $cmp;
+        let key = $key;
+        if key(&cmp) < key(&$base) {
+            $base = cmp;
+            true
+        } else {
+            false
+        }
+    }};
+}
+
+macro_rules! chmax_by_key {
+    ($base:expr, $cmp:expr, $key:expr $(,)*) => {{
+        let cmp = $cmp;
+        let key = $key;
+        if key(&cmp) > key(&$base) {
+            $base = cmp;
+            true
+        } else {
+            false
+        }
+    }};
+}
+
+// 最良値と一緒に、そのときの添字などの付随情報も更新する。`PartialOrd` なら浮動小数点数でも使える。
+// 使用例:
+//     let (mut best, mut best_i) = (INF, 0);
+//     for i in 0..N {
+//         chmin_with!(best, best_i, A[i], i);
+//     }
+macro_rules! chmin_with {
+    ($base:expr, $payload:expr, $cmp:expr, $cmp_payload:expr $(,)*) => {{
+        let cmp = $cmp;
+        if $base > cmp {
+            $base = cmp;
+            $payload = $cmp_payload;
+            true
+        } else {
+            false
+        }
+    }};
+}
+
+macro_rules! chmax_with {
+    ($base:expr, $payload:expr, $cmp:expr, $cmp_payload:expr $(,)*) => {{
+        let cmp = $cmp;
+        if $base < cmp {
+            $base = cmp;
+            $payload = $cmp_payload;
+            true
+        } else {
+            false
+        }
+    }};
+}
+
 macro_rules! pad {
     ($vec:expr, $($pad:expr),+ $(,)*) => {{
         let mut padded = vec![$($pad),+];
@@ -79,258 +184,3987 @@ macro_rules! pad {
     }};
 }
 
+// デバッグ出力。`debug_assertions` が無効（リリースビルド・提出時）の場合は何も出力せず、引数も評価しない。
+// 使用例: debug!(n, a[i]); // => [src/bin/a.rs:12] n = 3, a[i] = 5
+macro_rules! debug {
+    ($($val:expr),+ $(,)*) => {{
+        #[cfg(debug_assertions)]
+        {
+            let values = vec![$(format!("{} = {:?}", stringify!($val), $val)),+];
+            eprintln!("[{}:{}] {}", file!(), line!(), values.join(", "));
+        }
+    }};
+}
+
+// 2次元の表（`Vec<Vec<T>>` など、`T: Display`）を列ごとに右揃えして出力する。
+// 使用例: debug_grid!(dp);
+macro_rules! debug_grid {
+    ($grid:expr $(,)*) => {{
+        #[cfg(debug_assertions)]
+        {
+            let cells = $grid
+                .iter()
+                .map(|row| row.iter().map(|x| x.to_string()).collect::<Vec<String>>())
+                .collect::<Vec<_>>();
+            let mut widths: Vec<usize> = Vec::new();
+            for row in &cells {
+                for (j, cell) in row.iter().enumerate() {
+                    if j == widths.len() {
+                        widths.push(0);
+                    }
+                    widths[j] = widths[j].max(cell.chars().count());
+                }
+            }
+            eprintln!("[{}:{}] {} =", file!(), line!(), stringify!($grid));
+            for row in &cells {
+                let line = row
+                    .iter()
+                    .zip(&widths)
+                    .map(|(cell, &w)| format!("{:>w$}", cell, w = w))
+                    .collect::<Vec<_>>()
+                    .join(" ");
+                eprintln!("    {}", line);
+            }
+        }
+    }};
+}
+
+// ビットマスクを下位 `width` 桁の2進数で出力する。
+// 使用例: debug_bits!(mask, n); // => [src/bin/a.rs:12] mask = 0b00101
+macro_rules! debug_bits {
+    ($mask:expr, $width:expr $(,)*) => {{
+        #[cfg(debug_assertions)]
+        {
+            eprintln!(
+                "[{}:{}] {} = 0b{:0w$b}",
+                file!(),
+                line!(),
+                stringify!($mask),
+                $mask,
+                w = $width
+            );
+        }
+    }};
+}
+
+// 種類ごとに形式の異なるクエリ（"1 x y" / "2 k" / "3" など）を表す enum を定義し、
+// proconio の `Readable` を実装する。先頭の整数で種類を判別し、続くフィールドを順に読む。
+// フィールドの型には `Usize1` や `Chars` などのマーカーも使える。
+// `input!` 経由で読むため、インタラクティブ問題の `LineSource` からもそのまま読める。
+// 使用例:
+//     define_query! {
+//         Query {
+//             1 => Add { x: Usize1, y: i64 },
+//             2 => Get { k: usize },
+//             3 => Print,
+//         }
+//     }
+//     input! { Q: usize, queries: [Query; Q] }
+//     for q in queries {
+//         match q {
+//             Query::Add { x, y } => {}
+//             Query::Get { k } => {}
+//             Query::Print => {}
+//         }
+//     }
+macro_rules! define_query {
+    ($name:ident {
+        $($tag:literal => $variant:ident $({ $($field:ident : $ty:ty),* $(,)* })?),+ $(,)*
+    }) => {
+        #[derive(Clone, Debug, PartialEq)]
+        enum $name {
+            $($variant $({ $($field: <$ty as proconio::source::Readable>::Output),* })?),+
+        }
+
+        impl proconio::source::Readable for $name {
+            type Output = $name;
+            fn read<R: std::io::BufRead, S: proconio::source::Source<R>>(source: &mut S) -> $name {
+                let tag = <i64 as proconio::source::Readable>::read(source);
+                match tag {
+                    $($tag => {
+                        $($(let $field = <$ty as proconio::source::Readable>::read(source);)*)?
+                        $name::$variant $({ $($field),* })?
+                    })+
+                    _ => panic!("unknown query type: {}", tag),
+                }
+            }
+        }
+    };
+}
+
+// 出力用マクロ。いずれも `println!` に展開されるため、`#[fastout]` 付きの main 内でもバッファリングされる。
+// 使用例:
+//     print_yes_no!(a == b); // => Yes / No
+//     print_winner!(takahashi_wins); // => Takahashi / Aoki
+//     print_vec!(&ans); // => 1 2 3
+//     print_vec!(&idx, "\n", 1); // 0始まりの添字を1始まりにして改行区切りで出力
+//     print_grid!(grid); // => 各行を区切り文字なしで出力
+//     print_float!(ans, 10); // => 小数点以下10桁で出力
+macro_rules! print_yes_no {
+    ($cond:expr $(,)*) => {{
+        println!("{}", yes_no($cond));
+    }};
+}
+
+macro_rules! print_winner {
+    ($takahashi_wins:expr $(,)*) => {{
+        println!("{}", winner($takahashi_wins));
+    }};
+}
+
+macro_rules! print_vec {
+    ($iter:expr $(,)*) => {{
+        println!("{}", join_with($iter, " "));
+    }};
+    ($iter:expr, $sep:expr $(,)*) => {{
+        println!("{}", join_with($iter, $sep));
+    }};
+    ($iter:expr, $sep:expr, $offset:expr $(,)*) => {{
+        println!("{}", join_with_offset($iter, $sep, $offset));
+    }};
+}
+
+macro_rules! print_grid {
+    ($grid:expr $(,)*) => {{
+        println!("{}", grid_to_string(&$grid));
+    }};
+}
+
+macro_rules! print_float {
+    ($x:expr, $decimals:expr $(,)*) => {{
+        println!("{}", float_to_string($x, $decimals));
+    }};
+}
+
+/***********************************************************
+* Min / Max
+************************************************************/
+/// 最小値とその位置（最初に現れたもの）を返す。空の場合は `None` 。
+/// `PartialOrd` で比較するため浮動小数点数にも使える。NaN は無視する。
+///
+/// # 例
+///
+/// ```
+/// let a = vec![3.0, 1.0, 2.0, 1.0];
+/// assert_eq!(argmin(&a), Some((1, &1.0)));
+/// let (i, _) = argmin(a.iter().map(|x| (x - 1.8_f64).abs())).unwrap();
+/// assert_eq!(i, 2);
+/// ```
+fn argmin<I>(iter: I) -> Option<(usize, I::Item)>
+where
+    I: IntoIterator,
+    I::Item: PartialOrd,
+{
+    let mut best: Option<(usize, I::Item)> = None;
+    for (i, x) in iter.into_iter().enumerate() {
+        // 自分自身と比較できない値（NaN）は無視する
+        if x.partial_cmp(&x).is_none() {
+            continue;
+        }
+        if best.as_ref().map_or(true, |(_, b)| x < *b) {
+            best = Some((i, x));
+        }
+    }
+    best
+}
+
+/// 最大値とその位置（最初に現れたもの）を返す。空の場合は `None` 。
+/// `PartialOrd` で比較するため浮動小数点数にも使える。NaN は無視する。
+///
+/// # 例
+///
+/// ```
+/// let a = vec![3, 5, 2, 5];
+/// assert_eq!(argmax(&a), Some((1, &5)));
+/// ```
+fn argmax<I>(iter: I) -> Option<(usize, I::Item)>
+where
+    I: IntoIterator,
+    I::Item: PartialOrd,
+{
+    let mut best: Option<(usize, I::Item)> = None;
+    for (i, x) in iter.into_iter().enumerate() {
+        // 自分自身と比較できない値（NaN）は無視する
+        if x.partial_cmp(&x).is_none() {
+            continue;
+        }
+        if best.as_ref().map_or(true, |(_, b)| x > *b) {
+            best = Some((i, x));
+        }
+    }
+    best
+}
+
+/***********************************************************
+* Bitwise Calculations
+************************************************************/
+/// 整数 `num` の `shift` ビット目が1であるかどうかを確認する。
+/// ビット位置は0から始まり、0が最下位ビットを表す。
+///
+/// # 引数
+///
+/// * `num` - 判定対象の整数。
+/// * `shift` - チェックするビットの位置。0が最下位ビット。
+///
+/// # 戻り値
+///
+/// 指定されたビット位置にビットが立っている場合は `true`、そうでない場合は `false` 。
+fn has_bit(num: i64, shift: u32) -> bool {
+    ((num >> shift) & 1) == 1
+}
+
+/// 立っているビットの個数を返す。
+fn popcount(num: i64) -> u32 {
+    num.count_ones()
+}
+
+/// 立っているビットの個数を返す（`usize` 版）。
+fn popcount_usize(num: usize) -> u32 {
+    num.count_ones()
+}
+
+/// 最下位の立っているビットの位置を返す。`num == 0` の場合は `None` 。
+fn lowest_bit(num: i64) -> Option<u32> {
+    if num == 0 {
+        None
+    } else {
+        Some(num.trailing_zeros())
+    }
+}
+
+/// 最下位の立っているビットの位置を返す（`usize` 版）。`num == 0` の場合は `None` 。
+fn lowest_bit_usize(num: usize) -> Option<u32> {
+    if num == 0 {
+        None
+    } else {
+        Some(num.trailing_zeros())
+    }
+}
+
+/// 最上位の立っているビットの位置を返す。`num <= 0` の場合は `None` 。
+fn highest_bit(num: i64) -> Option<u32> {
+    if num <= 0 {
+        None
+    } else {
+        Some(63 - num.leading_zeros())
+    }
+}
+
+/// 最上位の立っているビットの位置を返す（`usize` 版）。`num == 0` の場合は `None` 。
+fn highest_bit_usize(num: usize) -> Option<u32> {
+    if num == 0 {
+        None
+    } else {
+        Some(usize::BITS - 1 - num.leading_zeros())
+    }
+}
+
+/// `usize` の `shift` ビット目が1であるかどうかを確認する。
+fn has_bit_usize(num: usize, shift: u32) -> bool {
+    ((num >> shift) & 1) == 1
+}
+
+/// `mask` の部分集合を降順に列挙するイテレータ。`mask` 自身と空集合 `0` を含む。
+///
+/// # 例
+///
+/// ```
+/// let subs = submasks(0b101).collect::<Vec<_>>();
+/// assert_eq!(subs, vec![0b101, 0b100, 0b001, 0b000]);
+/// ```
+fn submasks(mask: usize) -> Submasks {
+    Submasks {
+        mask,
+        cur: Some(mask),
+    }
+}
+
+struct Submasks {
+    mask: usize,
+    cur: Option<usize>,
+}
+
+impl Iterator for Submasks {
+    type Item = usize;
+    fn next(&mut self) -> Option<usize> {
+        let cur = self.cur?;
+        self.cur = if cur == 0 {
+            None
+        } else {
+            Some((cur - 1) & self.mask)
+        };
+        Some(cur)
+    }
+}
+
+/// `n` ビットの範囲で `mask` を含む集合（上位集合）を昇順に列挙するイテレータ。
+/// `mask` 自身と全体集合 `(1 << n) - 1` を含む。
+///
+/// # 例
+///
+/// ```
+/// let sups = supermasks(0b001, 3).collect::<Vec<_>>();
+/// assert_eq!(sups, vec![0b001, 0b011, 0b101, 0b111]);
+/// ```
+fn supermasks(mask: usize, n: usize) -> Supermasks {
+    Supermasks {
+        mask,
+        full: (1 << n) - 1,
+        cur: Some(mask),
+    }
+}
+
+struct Supermasks {
+    mask: usize,
+    full: usize,
+    cur: Option<usize>,
+}
+
+impl Iterator for Supermasks {
+    type Item = usize;
+    fn next(&mut self) -> Option<usize> {
+        let cur = self.cur?;
+        let next = (cur + 1) | self.mask;
+        self.cur = if next > self.full { None } else { Some(next) };
+        Some(cur)
+    }
+}
+
+/// `n` 個の要素から `k` 個を選ぶ集合をビットマスクとして昇順に列挙するイテレータ（Gosper's hack）。
+///
+/// # 例
+///
+/// ```
+/// let combs = combinations_mask(4, 2).collect::<Vec<_>>();
+/// assert_eq!(combs, vec![0b0011, 0b0101, 0b0110, 0b1001, 0b1010, 0b1100]);
+/// ```
+fn combinations_mask(n: usize, k: usize) -> CombinationsMask {
+    CombinationsMask {
+        limit: 1 << n,
+        cur: if k <= n { Some((1 << k) - 1) } else { None },
+    }
+}
+
+struct CombinationsMask {
+    limit: usize,
+    cur: Option<usize>,
+}
+
+impl Iterator for CombinationsMask {
+    type Item = usize;
+    fn next(&mut self) -> Option<usize> {
+        let cur = self.cur?;
+        self.cur = if cur == 0 {
+            None
+        } else {
+            let x = cur & cur.wrapping_neg();
+            let y = cur + x;
+            let next = (((cur & !y) / x) >> 1) | y;
+            if next < self.limit {
+                Some(next)
+            } else {
+                None
+            }
+        };
+        Some(cur)
+    }
+}
+
+/// `mask` の立っているビットの位置を昇順に列挙するイテレータ。
+///
+/// # 例
+///
+/// ```
+/// let bits = bit_indices(0b10110).collect::<Vec<_>>();
+/// assert_eq!(bits, vec![1, 2, 4]);
+/// ```
+fn bit_indices(mask: usize) -> BitIndices {
+    BitIndices { rest: mask }
+}
+
+struct BitIndices {
+    rest: usize,
+}
+
+impl Iterator for BitIndices {
+    type Item = usize;
+    fn next(&mut self) -> Option<usize> {
+        if self.rest == 0 {
+            return None;
+        }
+        let i = self.rest.trailing_zeros() as usize;
+        self.rest &= self.rest - 1;
+        Some(i)
+    }
+}
+
+/***********************************************************
+* Zeta / Mobius Transform
+************************************************************/
+/// 部分集合についての高速ゼータ変換
+///
+/// 長さ `2^n` の配列 `a` を、`a[S] = op(a[T] for T ⊆ S)` に書き換える。
+/// `op` は可換な二項演算であればよい（和・max・min・ModInt の和など）。計算量は O(2^n n)。
+///
+/// # 例
+///
+/// ```
+/// let mut a = vec![1, 2, 3, 4];
+/// subset_zeta(&mut a, |x, y| x + y);
+/// assert_eq!(a, vec![1, 3, 4, 10]);
+/// ```
+fn subset_zeta<T: Clone>(a: &mut [T], op: impl Fn(&T, &T) -> T) {
+    assert!(a.len().is_power_of_two());
+    let n = a.len();
+    let mut bit = 1;
+    while bit < n {
+        for s in 0..n {
+            if s & bit != 0 {
+                a[s] = op(&a[s], &a[s ^ bit]);
+            }
+        }
+        bit <<= 1;
+    }
+}
+
+/// 部分集合についての高速メビウス変換（`subset_zeta` の逆変換）
+///
+/// `inv_op(x, y)` には `op` の逆演算（和なら差）を渡す。計算量は O(2^n n)。
+///
+/// # 例
+///
+/// ```
+/// let mut a = vec![1, 3, 4, 10];
+/// subset_mobius(&mut a, |x, y| x - y);
+/// assert_eq!(a, vec![1, 2, 3, 4]);
+/// ```
+fn subset_mobius<T: Clone>(a: &mut [T], inv_op: impl Fn(&T, &T) -> T) {
+    assert!(a.len().is_power_of_two());
+    let n = a.len();
+    let mut bit = 1;
+    while bit < n {
+        for s in 0..n {
+            if s & bit != 0 {
+                a[s] = inv_op(&a[s], &a[s ^ bit]);
+            }
+        }
+        bit <<= 1;
+    }
+}
+
+/// 上位集合についての高速ゼータ変換
+///
+/// 長さ `2^n` の配列 `a` を、`a[S] = op(a[T] for T ⊇ S)` に書き換える。計算量は O(2^n n)。
+///
+/// # 例
+///
+/// ```
+/// let mut a = vec![1, 2, 3, 4];
+/// superset_zeta(&mut a, |x, y| *x.max(y));
+/// assert_eq!(a, vec![4, 4, 4, 4]);
+/// ```
+fn superset_zeta<T: Clone>(a: &mut [T], op: impl Fn(&T, &T) -> T) {
+    assert!(a.len().is_power_of_two());
+    let n = a.len();
+    let mut bit = 1;
+    while bit < n {
+        for s in 0..n {
+            if s & bit == 0 {
+                a[s] = op(&a[s], &a[s | bit]);
+            }
+        }
+        bit <<= 1;
+    }
+}
+
+/// 上位集合についての高速メビウス変換（`superset_zeta` の逆変換）
+///
+/// `inv_op(x, y)` には `op` の逆演算（和なら差）を渡す。計算量は O(2^n n)。
+fn superset_mobius<T: Clone>(a: &mut [T], inv_op: impl Fn(&T, &T) -> T) {
+    assert!(a.len().is_power_of_two());
+    let n = a.len();
+    let mut bit = 1;
+    while bit < n {
+        for s in 0..n {
+            if s & bit == 0 {
+                a[s] = inv_op(&a[s], &a[s | bit]);
+            }
+        }
+        bit <<= 1;
+    }
+}
+
+/// 部分集合畳み込み
+///
+/// 長さ `2^n` の配列 `f`, `g` について、`h[S] = Σ f[T] * g[S \ T] (T ⊆ S)` を求める。
+/// 要素型は `i64` や `ModInt` など、`Default::default()` を0とする環であればよい。
+/// 計算量は O(2^n n^2)。
+///
+/// # 例
+///
+/// ```
+/// let f = vec![1, 2, 3, 4];
+/// let g = vec![5, 6, 7, 8];
+/// let h = subset_convolution(&f, &g);
+/// // h[0b11] = f[00]g[11] + f[01]g[10] + f[10]g[01] + f[11]g[00]
+/// assert_eq!(h, vec![5, 16, 22, 60]);
+/// ```
+fn subset_convolution<T>(f: &[T], g: &[T]) -> Vec<T>
+where
+    T: Copy
+        + Default
+        + std::ops::Add<Output = T>
+        + std::ops::Sub<Output = T>
+        + std::ops::Mul<Output = T>,
+{
+    assert!(f.len().is_power_of_two());
+    assert_eq!(f.len(), g.len());
+    let len = f.len();
+    let n = len.trailing_zeros() as usize;
+    let zero = T::default();
+    let mut fr = vec![vec![zero; len]; n + 1];
+    let mut gr = vec![vec![zero; len]; n + 1];
+    for s in 0..len {
+        let k = s.count_ones() as usize;
+        fr[k][s] = f[s];
+        gr[k][s] = g[s];
+    }
+    for k in 0..=n {
+        subset_zeta(&mut fr[k], |&x, &y| x + y);
+        subset_zeta(&mut gr[k], |&x, &y| x + y);
+    }
+    let mut h = vec![zero; len];
+    let mut hr = vec![zero; len];
+    for k in 0..=n {
+        for s in 0..len {
+            let mut acc = zero;
+            for i in 0..=k {
+                acc = acc + fr[i][s] * gr[k - i][s];
+            }
+            hr[s] = acc;
+        }
+        subset_mobius(&mut hr, |&x, &y| x - y);
+        for s in 0..len {
+            if s.count_ones() as usize == k {
+                h[s] = hr[s];
+            }
+        }
+    }
+    h
+}
+
+/***********************************************************
+* BitSet
+************************************************************/
+/// 長さを実行時に指定できるビットセット
+///
+/// 64ビット単位のシフトと論理演算により、部分和 DP などを O(NW/64) で計算できる。
+/// 長さ `n` を超えたビットはシフト時に捨てられる。
+///
+/// # 例
+///
+/// ```
+/// // 部分和問題: a の部分集合の和として w 以下のどの値が作れるか
+/// let mut dp = BitSet::new(w + 1);
+/// dp.set(0, true);
+/// for &x in &a {
+///     dp |= &dp << x;
+/// }
+/// println!("{}", if dp.get(w) { "Yes" } else { "No" });
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+struct BitSet {
+    n: usize,
+    data: Vec<u64>,
+}
+
+impl BitSet {
+    /// 全ビットが0の長さ `n` のビットセットを作る。
+    fn new(n: usize) -> Self {
+        BitSet {
+            n,
+            data: vec![0; (n + 63) / 64],
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.n
+    }
+
+    fn get(&self, i: usize) -> bool {
+        assert!(i < self.n);
+        (self.data[i / 64] >> (i % 64)) & 1 == 1
+    }
+
+    fn set(&mut self, i: usize, value: bool) {
+        assert!(i < self.n);
+        if value {
+            self.data[i / 64] |= 1 << (i % 64);
+        } else {
+            self.data[i / 64] &= !(1 << (i % 64));
+        }
+    }
+
+    /// 全ビットを `value` にする。
+    fn fill(&mut self, value: bool) {
+        let w = if value { !0 } else { 0 };
+        self.data.iter_mut().for_each(|x| *x = w);
+        self.trim();
+    }
+
+    /// 立っているビットの個数を返す。
+    fn count_ones(&self) -> usize {
+        self.data.iter().map(|x| x.count_ones() as usize).sum()
+    }
+
+    /// 立っているビットのうち最小の位置を返す。
+    fn first_one(&self) -> Option<usize> {
+        self.next_one(0)
+    }
+
+    /// 位置 `i` 以降で立っているビットのうち最小の位置を返す。
+    fn next_one(&self, i: usize) -> Option<usize> {
+        if i >= self.n {
+            return None;
+        }
+        let mut w = i / 64;
+        let mut x = self.data[w] & (!0 << (i % 64));
+        loop {
+            if x != 0 {
+                return Some(w * 64 + x.trailing_zeros() as usize);
+            }
+            w += 1;
+            if w == self.data.len() {
+                return None;
+            }
+            x = self.data[w];
+        }
+    }
+
+    /// 立っているビットの位置を昇順に列挙する。
+    fn iter_ones(&self) -> impl Iterator<Item = usize> + '_ {
+        std::iter::successors(self.first_one(), move |&i| self.next_one(i + 1))
+    }
+
+    /// 長さ `n` を超えた部分のビットを0にする。
+    fn trim(&mut self) {
+        if self.n % 64 != 0 {
+            if let Some(last) = self.data.last_mut() {
+                *last &= (1 << (self.n % 64)) - 1;
+            }
+        }
+    }
+}
+
+impl std::ops::ShlAssign<usize> for BitSet {
+    fn shl_assign(&mut self, k: usize) {
+        let len = self.data.len();
+        let (ws, bs) = (k / 64, k % 64);
+        for i in (0..len).rev() {
+            self.data[i] = if i < ws {
+                0
+            } else if bs == 0 {
+                self.data[i - ws]
+            } else if i == ws {
+                self.data[0] << bs
+            } else {
+                (self.data[i - ws] << bs) | (self.data[i - ws - 1] >> (64 - bs))
+            };
+        }
+        self.trim();
+    }
+}
+
+impl std::ops::ShrAssign<usize> for BitSet {
+    fn shr_assign(&mut self, k: usize) {
+        let len = self.data.len();
+        let (ws, bs) = (k / 64, k % 64);
+        for i in 0..len {
+            self.data[i] = if i + ws >= len {
+                0
+            } else if bs == 0 {
+                self.data[i + ws]
+            } else if i + ws + 1 == len {
+                self.data[i + ws] >> bs
+            } else {
+                (self.data[i + ws] >> bs) | (self.data[i + ws + 1] << (64 - bs))
+            };
+        }
+    }
+}
+
+impl std::ops::Shl<usize> for &BitSet {
+    type Output = BitSet;
+    fn shl(self, k: usize) -> BitSet {
+        let mut res = self.clone();
+        res <<= k;
+        res
+    }
+}
+
+impl std::ops::Shl<usize> for BitSet {
+    type Output = BitSet;
+    fn shl(mut self, k: usize) -> BitSet {
+        self <<= k;
+        self
+    }
+}
+
+impl std::ops::Shr<usize> for &BitSet {
+    type Output = BitSet;
+    fn shr(self, k: usize) -> BitSet {
+        let mut res = self.clone();
+        res >>= k;
+        res
+    }
+}
+
+impl std::ops::Shr<usize> for BitSet {
+    type Output = BitSet;
+    fn shr(mut self, k: usize) -> BitSet {
+        self >>= k;
+        self
+    }
+}
+
+macro_rules! impl_bitset_binop {
+    ($assign_trait:ident, $assign_fn:ident, $trait:ident, $fn:ident, $op:tt) => {
+        impl std::ops::$assign_trait<&BitSet> for BitSet {
+            fn $assign_fn(&mut self, rhs: &BitSet) {
+                assert_eq!(self.n, rhs.n);
+                for (x, y) in self.data.iter_mut().zip(&rhs.data) {
+                    *x = *x $op *y;
+                }
+            }
+        }
+
+        impl std::ops::$assign_trait<BitSet> for BitSet {
+            fn $assign_fn(&mut self, rhs: BitSet) {
+                std::ops::$assign_trait::$assign_fn(self, &rhs);
+            }
+        }
+
+        impl std::ops::$trait<&BitSet> for &BitSet {
+            type Output = BitSet;
+            fn $fn(self, rhs: &BitSet) -> BitSet {
+                let mut res = self.clone();
+                std::ops::$assign_trait::$assign_fn(&mut res, rhs);
+                res
+            }
+        }
+    };
+}
+impl_bitset_binop!(BitOrAssign, bitor_assign, BitOr, bitor, |);
+impl_bitset_binop!(BitAndAssign, bitand_assign, BitAnd, bitand, &);
+impl_bitset_binop!(BitXorAssign, bitxor_assign, BitXor, bitxor, ^);
+
+/***********************************************************
+* Input
+************************************************************/
+/// 2次元グリッド
+///
+/// 要素を行優先の1次元配列で保持する。`grid[(i, j)]` で `i` 行 `j` 列目にアクセスする。
+/// `Display` は各行を区切り文字なしで出力する（文字のグリッド向け）。
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+struct Grid<T> {
+    h: usize,
+    w: usize,
+    data: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// 全要素が `fill` の `h` 行 `w` 列のグリッドを作る。
+    fn new(h: usize, w: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            h,
+            w,
+            data: vec![fill; h * w],
+        }
+    }
+
+    /// 各行の長さが等しい `Vec<Vec<T>>` からグリッドを作る。
+    fn from_rows(rows: Vec<Vec<T>>) -> Self {
+        let h = rows.len();
+        let w = rows.first().map_or(0, |row| row.len());
+        let mut data = Vec::with_capacity(h * w);
+        for row in rows {
+            assert_eq!(row.len(), w);
+            data.extend(row);
+        }
+        Grid { h, w, data }
+    }
+
+    fn h(&self) -> usize {
+        self.h
+    }
+
+    fn w(&self) -> usize {
+        self.w
+    }
+
+    /// `i` 行目をスライスとして返す。
+    fn row(&self, i: usize) -> &[T] {
+        &self.data[i * self.w..(i + 1) * self.w]
+    }
+
+    /// `i` 行目を可変スライスとして返す。
+    fn row_mut(&mut self, i: usize) -> &mut [T] {
+        &mut self.data[i * self.w..(i + 1) * self.w]
+    }
+
+    /// 各行をスライスとして順に返す。
+    fn rows(&self) -> impl Iterator<Item = &[T]> + '_ {
+        (0..self.h).map(move |i| self.row(i))
+    }
+
+    /// 範囲外を許す（負の添字も可）アクセス。範囲外なら `None` 。
+    fn get(&self, i: i64, j: i64) -> Option<&T> {
+        if 0 <= i && i < self.h as i64 && 0 <= j && j < self.w as i64 {
+            Some(&self.data[i as usize * self.w + j as usize])
+        } else {
+            None
+        }
+    }
+
+    /// `(i, j)` の上下左右で、グリッド内にあるマスを返す。
+    fn neighbors4(&self, i: usize, j: usize) -> impl Iterator<Item = (usize, usize)> {
+        let (h, w) = (self.h, self.w);
+        [(!0, 0), (1, 0), (0, !0), (0, 1)]
+            .iter()
+            .map(move |&(di, dj): &(usize, usize)| (i.wrapping_add(di), j.wrapping_add(dj)))
+            .filter(move |&(ni, nj)| ni < h && nj < w)
+    }
+
+    /// `x` と等しい最初のマスを行優先で探す。
+    fn find(&self, x: &T) -> Option<(usize, usize)>
+    where
+        T: PartialEq,
+    {
+        self.data
+            .iter()
+            .position(|y| y == x)
+            .map(|p| (p / self.w, p % self.w))
+    }
+
+    /// 周囲を幅1の番兵 `fill` で囲んだ `(h + 2)` 行 `(w + 2)` 列のグリッドを返す。
+    /// 元の `(i, j)` は `(i + 1, j + 1)` に移るため、1-indexed としても扱える。
+    fn with_border(&self, fill: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let mut res = Grid::new(self.h + 2, self.w + 2, fill);
+        for i in 0..self.h {
+            res.row_mut(i + 1)[1..=self.w].clone_from_slice(self.row(i));
+        }
+        res
+    }
+
+    /// 先頭行・先頭列に `T::default()` のダミーを挿入し、1-indexed で扱えるようにしたグリッドを返す。
+    fn one_indexed(&self) -> Grid<T>
+    where
+        T: Clone + Default,
+    {
+        let mut res = Grid::new(self.h + 1, self.w + 1, T::default());
+        for i in 0..self.h {
+            res.row_mut(i + 1)[1..].clone_from_slice(self.row(i));
+        }
+        res
+    }
+
+    /// `Vec<Vec<T>>` に変換する。
+    fn to_vec(&self) -> Vec<Vec<T>>
+    where
+        T: Clone,
+    {
+        self.rows().map(|row| row.to_vec()).collect()
+    }
+}
+
+impl<T> std::ops::Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+    fn index(&self, (i, j): (usize, usize)) -> &T {
+        assert!(i < self.h && j < self.w);
+        &self.data[i * self.w + j]
+    }
+}
+
+impl<T> std::ops::IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (i, j): (usize, usize)) -> &mut T {
+        assert!(i < self.h && j < self.w);
+        &mut self.data[i * self.w + j]
+    }
+}
+
+impl<T: std::fmt::Display> std::fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        for (i, row) in self.rows().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{}", join_with(row, ""))?;
+        }
+        Ok(())
+    }
+}
+
+/// `source` から空白区切りの `h` 行 `w` 列のグリッドを読み込む。
+/// `is_1_indexed` が true の場合は、先頭行・先頭列に `T::default()` のダミーを挿入して返す。
+///
+/// `read_grid` などの読み込み関数（グラフの `read_graph` なども同様）には main の `LineSource` を渡す。
+/// main が標準入力をロックしているため、`from` なしの `input!` と混ぜるとロック待ちで止まる。
+///
+/// # 例
+///
+/// ```
+/// // 3 4
+/// // 1 2 3 4
+/// // ...
+/// input! { from &mut source, H: usize, W: usize }
+/// let A: Grid<i64> = read_grid(&mut source, H, W, false);
+/// ```
+fn read_grid<T, R, S>(source: &mut S, h: usize, w: usize, is_1_indexed: bool) -> Grid<T>
+where
+    T: FromStr + Default + Clone,
+    <T as FromStr>::Err: Debug,
+    R: std::io::BufRead,
+    S: proconio::source::Source<R>,
+{
+    let mut data = Vec::with_capacity(h * w);
+    for _ in 0..h {
+        input! {
+            from &mut *source,
+            row: [T; w],
+        }
+        data.extend(row);
+    }
+    let grid = Grid { h, w, data };
+    if is_1_indexed {
+        grid.one_indexed()
+    } else {
+        grid
+    }
+}
+
+/// `source` から区切り文字のない `h` 行の文字グリッド（`#.#.` のような形式）を読み込む。
+/// 列数は1行目の長さから決まる。
+/// `is_1_indexed` が true の場合は、先頭行・先頭列に `'\0'` のダミーを挿入して返す。
+/// 番兵で囲みたい場合は `read_char_grid(&mut source, h, false).with_border('#')` のようにする。
+fn read_char_grid<R, S>(source: &mut S, h: usize, is_1_indexed: bool) -> Grid<char>
+where
+    R: std::io::BufRead,
+    S: proconio::source::Source<R>,
+{
+    let mut rows = Vec::with_capacity(h);
+    for _ in 0..h {
+        input! {
+            from &mut *source,
+            row: Chars,
+        }
+        rows.push(row);
+    }
+    let grid = Grid::from_rows(rows);
+    if is_1_indexed {
+        grid.one_indexed()
+    } else {
+        grid
+    }
+}
+
+/// `read_char_grid` のバイト列版。
+fn read_byte_grid<R, S>(source: &mut S, h: usize, is_1_indexed: bool) -> Grid<u8>
+where
+    R: std::io::BufRead,
+    S: proconio::source::Source<R>,
+{
+    let mut rows = Vec::with_capacity(h);
+    for _ in 0..h {
+        input! {
+            from &mut *source,
+            row: Bytes,
+        }
+        rows.push(row);
+    }
+    let grid = Grid::from_rows(rows);
+    if is_1_indexed {
+        grid.one_indexed()
+    } else {
+        grid
+    }
+}
+
+/***********************************************************
+* Graph
+************************************************************/
+/// 辺のリストから隣接リストを作る。頂点番号は0始まり。
+/// `directed` が false の場合は両方向の辺を追加する。
+fn adjacency_list(n: usize, edges: &[(usize, usize)], directed: bool) -> Vec<Vec<usize>> {
+    let mut g = vec![Vec::new(); n];
+    for &(u, v) in edges {
+        g[u].push(v);
+        if !directed {
+            g[v].push(u);
+        }
+    }
+    g
+}
+
+/// 重み付き辺のリストから隣接リストを作る。頂点番号は0始まり。
+/// `directed` が false の場合は両方向の辺を追加する。
+fn weighted_adjacency_list<W: Copy>(
+    n: usize,
+    edges: &[(usize, usize, W)],
+    directed: bool,
+) -> Vec<Vec<(usize, W)>> {
+    let mut g = vec![Vec::new(); n];
+    for &(u, v, w) in edges {
+        g[u].push((v, w));
+        if !directed {
+            g[v].push((u, w));
+        }
+    }
+    g
+}
+
+/// CSR（Compressed Sparse Row）形式のグラフ
+///
+/// 全ての辺を1本の配列に詰めて保持するため、`Vec<Vec<_>>` よりメモリ効率・キャッシュ効率が良い。
+/// 頂点 `v` から出る辺は `g[v]` または `g.neighbors(v)` でスライスとして得られる。
+///
+/// # 例
+///
+/// ```
+/// let g = Csr::new(3, &[(0, 1), (0, 2), (2, 1)]);
+/// assert_eq!(g[0], [1, 2]);
+/// ```
+#[derive(Clone, Debug)]
+struct Csr<E> {
+    start: Vec<usize>,
+    edges: Vec<E>,
+}
+
+impl<E: Clone> Csr<E> {
+    /// 頂点数 `n` と `(始点, 辺の情報)` のリストから構築する。
+    fn new(n: usize, edges: &[(usize, E)]) -> Self {
+        let mut start = vec![0; n + 1];
+        for &(u, _) in edges {
+            start[u + 1] += 1;
+        }
+        for i in 0..n {
+            start[i + 1] += start[i];
+        }
+        let mut pos = start.clone();
+        let mut order = vec![0; edges.len()];
+        for (i, &(u, _)) in edges.iter().enumerate() {
+            order[pos[u]] = i;
+            pos[u] += 1;
+        }
+        Csr {
+            start,
+            edges: order.iter().map(|&i| edges[i].1.clone()).collect(),
+        }
+    }
+}
+
+impl<E> Csr<E> {
+    /// 頂点数を返す。
+    fn len(&self) -> usize {
+        self.start.len() - 1
+    }
+
+    /// 頂点 `v` から出る辺を返す。
+    fn neighbors(&self, v: usize) -> &[E] {
+        &self.edges[self.start[v]..self.start[v + 1]]
+    }
+}
+
+impl<E> std::ops::Index<usize> for Csr<E> {
+    type Output = [E];
+    fn index(&self, v: usize) -> &[E] {
+        self.neighbors(v)
+    }
+}
+
+/// `source` から1-indexedの `m` 本の辺 `u v` を読み込み、0-indexedの隣接リストを返す。
+/// 入力元についての注意は `read_grid` を参照。
+///
+/// # 例
+///
+/// ```
+/// input! { from &mut source, N: usize, M: usize }
+/// let g = read_graph(&mut source, N, M, false);
+/// ```
+fn read_graph<R, S>(source: &mut S, n: usize, m: usize, directed: bool) -> Vec<Vec<usize>>
+where
+    R: std::io::BufRead,
+    S: proconio::source::Source<R>,
+{
+    input! {
+        from &mut *source,
+        edges: [(Usize1, Usize1); m],
+    }
+    adjacency_list(n, &edges, directed)
+}
+
+/// `source` から1-indexedの `m` 本の重み付き辺 `u v w` を読み込み、0-indexedの隣接リストを返す。
+fn read_weighted_graph<W, R, S>(
+    source: &mut S,
+    n: usize,
+    m: usize,
+    directed: bool,
+) -> Vec<Vec<(usize, W)>>
+where
+    W: FromStr + Copy,
+    <W as FromStr>::Err: Debug,
+    R: std::io::BufRead,
+    S: proconio::source::Source<R>,
+{
+    input! {
+        from &mut *source,
+        edges: [(Usize1, Usize1, W); m],
+    }
+    weighted_adjacency_list(n, &edges, directed)
+}
+
+/// `source` から1-indexedの `m` 本の辺 `u v` を読み込み、0-indexedの CSR 形式のグラフを返す。
+fn read_csr_graph<R, S>(source: &mut S, n: usize, m: usize, directed: bool) -> Csr<usize>
+where
+    R: std::io::BufRead,
+    S: proconio::source::Source<R>,
+{
+    input! {
+        from &mut *source,
+        edges: [(Usize1, Usize1); m],
+    }
+    let mut list = Vec::with_capacity(if directed { m } else { 2 * m });
+    for &(u, v) in &edges {
+        list.push((u, v));
+        if !directed {
+            list.push((v, u));
+        }
+    }
+    Csr::new(n, &list)
+}
+
+/// `source` から1-indexedの `m` 本の重み付き辺 `u v w` を読み込み、
+/// 0-indexedの CSR 形式のグラフ（辺は `(行き先, 重み)`）を返す。
+fn read_weighted_csr_graph<W, R, S>(
+    source: &mut S,
+    n: usize,
+    m: usize,
+    directed: bool,
+) -> Csr<(usize, W)>
+where
+    W: FromStr + Copy,
+    <W as FromStr>::Err: Debug,
+    R: std::io::BufRead,
+    S: proconio::source::Source<R>,
+{
+    input! {
+        from &mut *source,
+        edges: [(Usize1, Usize1, W); m],
+    }
+    let mut list = Vec::with_capacity(if directed { m } else { 2 * m });
+    for &(u, v, w) in &edges {
+        list.push((u, (v, w)));
+        if !directed {
+            list.push((v, (u, w)));
+        }
+    }
+    Csr::new(n, &list)
+}
+
+/***********************************************************
+* Distance
+************************************************************/
+/// 無限大を持つ距離の型
+///
+/// `Dist::INF` に何を足しても `Dist::INF` のままで、有限値どうしの加算もオーバーフローせず `Dist::INF` に飽和する。
+/// `Ord` を実装しているため `chmin!` や `BinaryHeap` の `Reverse((Dist, usize))` にそのまま使える。
+/// `Display` では無限大を `-1` として出力する（別の表記にしたい場合は `to_string_or` を使う）。
+///
+/// # 例
+///
+/// ```
+/// let mut dp = vec![Dist::INF; N + 1];
+/// dp[1] = Dist::new(0);
+/// for i in 1..N {
+///     chmin!(dp[i + 1], dp[i] + (h[i - 1] - h[i]).abs());
+/// }
+/// println!("{}", dp[N]); // 到達不能なら -1
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+struct Dist(i64);
+
+impl Dist {
+    const INF: Dist = Dist(i64::MAX);
+
+    fn new(d: i64) -> Self {
+        Dist(d)
+    }
+
+    fn is_inf(self) -> bool {
+        self == Dist::INF
+    }
+
+    /// 有限なら `Some(距離)`、無限大なら `None` を返す。
+    fn get(self) -> Option<i64> {
+        if self.is_inf() {
+            None
+        } else {
+            Some(self.0)
+        }
+    }
+
+    /// 有限なら距離、無限大なら `default` を返す。
+    fn unwrap_or(self, default: i64) -> i64 {
+        self.get().unwrap_or(default)
+    }
+
+    /// 有限なら距離を、無限大なら `marker` を文字列にして返す。
+    fn to_string_or(self, marker: &str) -> String {
+        match self.get() {
+            Some(d) => d.to_string(),
+            None => marker.to_string(),
+        }
+    }
+}
+
+impl From<i64> for Dist {
+    fn from(d: i64) -> Self {
+        Dist(d)
+    }
+}
+
+impl std::ops::Add<i64> for Dist {
+    type Output = Dist;
+    fn add(self, rhs: i64) -> Dist {
+        if self.is_inf() {
+            Dist::INF
+        } else {
+            Dist(self.0.saturating_add(rhs))
+        }
+    }
+}
+
+impl std::ops::Add for Dist {
+    type Output = Dist;
+    fn add(self, rhs: Dist) -> Dist {
+        if rhs.is_inf() {
+            Dist::INF
+        } else {
+            self + rhs.0
+        }
+    }
+}
+
+impl std::ops::AddAssign<i64> for Dist {
+    fn add_assign(&mut self, rhs: i64) {
+        *self = *self + rhs;
+    }
+}
+
+impl std::ops::AddAssign for Dist {
+    fn add_assign(&mut self, rhs: Dist) {
+        *self = *self + rhs;
+    }
+}
+
+impl std::fmt::Display for Dist {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.unwrap_or(-1))
+    }
+}
+
+/// ダイクストラ法
+///
+/// 非負の重みを持つ隣接リスト `g`（`weighted_adjacency_list` などで作ったもの）について、
+/// 頂点 `s` からの最短距離を返す。到達できない頂点は `Dist::INF` 。計算量は O((N + M) log N)。
+fn dijkstra(g: &[Vec<(usize, i64)>], s: usize) -> Vec<Dist> {
+    let mut dist = vec![Dist::INF; g.len()];
+    let mut heap = BinaryHeap::new();
+    dist[s] = Dist::new(0);
+    heap.push(std::cmp::Reverse((dist[s], s)));
+    while let Some(std::cmp::Reverse((d, v))) = heap.pop() {
+        if dist[v] < d {
+            continue;
+        }
+        for &(u, w) in &g[v] {
+            if chmin!(dist[u], d + w) {
+                heap.push(std::cmp::Reverse((dist[u], u)));
+            }
+        }
+    }
+    dist
+}
+
+/***********************************************************
+* One-Indexed Vector
+************************************************************/
+/// 1始まりで添字アクセスするベクター
+///
+/// `pad!` でダミー要素を入れる代わりに使う。内部ではダミーを持たないため、
+/// `join_with(&a, " ")` などでそのまま出力できる。
+/// デバッグビルドでは添字0でのアクセスを検出して panic する。
+///
+/// # 例
+///
+/// ```
+/// input! { N: usize, P: [usize; N] }
+/// let P = OneIndexed::from(P);
+/// let x = P[1]; // 先頭の要素
+/// println!("{}", join_with(&P, " "));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+struct OneIndexed<T>(Vec<T>);
+
+impl<T> OneIndexed<T> {
+    /// 全要素が `fill` の長さ `n` のベクターを作る。添字は `1..=n` 。
+    fn new(n: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        OneIndexed(vec![fill; n])
+    }
+
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    fn push(&mut self, x: T) {
+        self.0.push(x);
+    }
+
+    fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.0.iter()
+    }
+
+    fn iter_mut(&mut self) -> std::slice::IterMut<'_, T> {
+        self.0.iter_mut()
+    }
+
+    /// 添字 `1..=n` と要素の組を列挙する。
+    fn enumerate(&self) -> impl Iterator<Item = (usize, &T)> + '_ {
+        self.0.iter().enumerate().map(|(i, x)| (i + 1, x))
+    }
+
+    /// 0始まりのスライスとして参照する。
+    fn as_slice(&self) -> &[T] {
+        &self.0
+    }
+
+    /// 0始まりの `Vec<T>` に戻す。
+    fn into_vec(self) -> Vec<T> {
+        self.0
+    }
+
+    /// 累積和 `s` を返す。`s[i]` は `1..=i` 番目の要素の和（`s[0]` は0）で、
+    /// `l..=r` 番目の和は `s[r] - s[l - 1]` で求まる。
+    fn prefix_sum(&self) -> Vec<T>
+    where
+        T: Copy + Default + std::ops::Add<Output = T>,
+    {
+        prefix_fold(&self.0, T::default(), |&x, &y| x + y)
+    }
+}
+
+impl<T> std::ops::Index<usize> for OneIndexed<T> {
+    type Output = T;
+    fn index(&self, i: usize) -> &T {
+        debug_assert!(i >= 1, "OneIndexed is accessed with index 0");
+        &self.0[i.wrapping_sub(1)]
+    }
+}
+
+impl<T> std::ops::IndexMut<usize> for OneIndexed<T> {
+    fn index_mut(&mut self, i: usize) -> &mut T {
+        debug_assert!(i >= 1, "OneIndexed is accessed with index 0");
+        &mut self.0[i.wrapping_sub(1)]
+    }
+}
+
+impl<T> From<Vec<T>> for OneIndexed<T> {
+    fn from(v: Vec<T>) -> Self {
+        OneIndexed(v)
+    }
+}
+
+impl<T> From<OneIndexed<T>> for Vec<T> {
+    fn from(v: OneIndexed<T>) -> Self {
+        v.0
+    }
+}
+
+impl<T> std::iter::FromIterator<T> for OneIndexed<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        OneIndexed(iter.into_iter().collect())
+    }
+}
+
+impl<T> IntoIterator for OneIndexed<T> {
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a OneIndexed<T> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+/// 1始まりで `(i, j)` の添字アクセスをする2次元配列
+///
+/// デバッグビルドでは行・列のどちらかが0のアクセスを検出して panic する。
+///
+/// # 例
+///
+/// ```
+/// let mut dp = OneIndexed2d::new(H, W, 0_i64);
+/// dp[(1, 1)] = 1;
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+struct OneIndexed2d<T> {
+    h: usize,
+    w: usize,
+    data: Vec<T>,
+}
+
+impl<T> OneIndexed2d<T> {
+    /// 全要素が `fill` の `h` 行 `w` 列の配列を作る。添字は `(1..=h, 1..=w)` 。
+    fn new(h: usize, w: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        OneIndexed2d {
+            h,
+            w,
+            data: vec![fill; h * w],
+        }
+    }
+
+    /// 0始まりの `Vec<Vec<T>>` から作る。
+    fn from_rows(rows: Vec<Vec<T>>) -> Self {
+        let h = rows.len();
+        let w = rows.first().map_or(0, |row| row.len());
+        let mut data = Vec::with_capacity(h * w);
+        for row in rows {
+            assert_eq!(row.len(), w);
+            data.extend(row);
+        }
+        OneIndexed2d { h, w, data }
+    }
+
+    fn h(&self) -> usize {
+        self.h
+    }
+
+    fn w(&self) -> usize {
+        self.w
+    }
+
+    /// `i` 行目（1始まり）を0始まりのスライスとして返す。
+    fn row(&self, i: usize) -> &[T] {
+        debug_assert!(i >= 1, "OneIndexed2d is accessed with row 0");
+        &self.data[(i - 1) * self.w..i * self.w]
+    }
+
+    /// 0始まりの `Vec<Vec<T>>` に戻す。
+    fn to_rows(&self) -> Vec<Vec<T>>
+    where
+        T: Clone,
+    {
+        (0..self.h)
+            .map(|i| self.data[i * self.w..(i + 1) * self.w].to_vec())
+            .collect()
+    }
+
+    /// 2次元累積和 `s` を返す。`s[i][j]` は `(1..=i, 1..=j)` の和（0行目・0列目は0）。
+    fn prefix_sum(&self) -> Vec<Vec<T>>
+    where
+        T: Copy + Default + std::ops::Add<Output = T> + std::ops::Sub<Output = T>,
+    {
+        CumSum2d::new(&self.to_rows()).s
+    }
+}
+
+impl<T> std::ops::Index<(usize, usize)> for OneIndexed2d<T> {
+    type Output = T;
+    fn index(&self, (i, j): (usize, usize)) -> &T {
+        debug_assert!(i >= 1 && j >= 1, "OneIndexed2d is accessed with index 0");
+        assert!(i <= self.h && j <= self.w);
+        &self.data[(i - 1) * self.w + (j - 1)]
+    }
+}
+
+impl<T> std::ops::IndexMut<(usize, usize)> for OneIndexed2d<T> {
+    fn index_mut(&mut self, (i, j): (usize, usize)) -> &mut T {
+        debug_assert!(i >= 1 && j >= 1, "OneIndexed2d is accessed with index 0");
+        assert!(i <= self.h && j <= self.w);
+        &mut self.data[(i - 1) * self.w + (j - 1)]
+    }
+}
+
+impl<T> From<Vec<Vec<T>>> for OneIndexed2d<T> {
+    fn from(rows: Vec<Vec<T>>) -> Self {
+        OneIndexed2d::from_rows(rows)
+    }
+}
+
+/***********************************************************
+* Output
+************************************************************/
+/// `true` なら `"Yes"`、`false` なら `"No"` を返す。
+fn yes_no(cond: bool) -> &'static str {
+    if cond {
+        "Yes"
+    } else {
+        "No"
+    }
+}
+
+/// `true` なら `"YES"`、`false` なら `"NO"` を返す。
+fn yes_no_upper(cond: bool) -> &'static str {
+    if cond {
+        "YES"
+    } else {
+        "NO"
+    }
+}
+
+/// 高橋君が勝つなら `TAKAHASHI`、そうでなければ `AOKI` を返す。
+fn winner(takahashi_wins: bool) -> &'static str {
+    if takahashi_wins {
+        TAKAHASHI
+    } else {
+        AOKI
+    }
+}
+
+/// 各要素に `offset` を足してから、指定された区切り文字で連結した文字列を返す。
+/// 0始まりの添字を1始まりで出力する場合などに使う。
+///
+/// # 例
+/// ```
+/// let idx = vec![0_usize, 2, 3];
+/// assert_eq!(join_with_offset(&idx, " ", 1), "1 3 4");
+/// ```
+fn join_with_offset<'a, I, T>(iter: I, sep: &str, offset: T) -> String
+where
+    I: IntoIterator<Item = &'a T>,
+    T: 'a + Copy + std::fmt::Display + std::ops::Add<Output = T>,
+{
+    join_with(iter.into_iter().map(|&x| x + offset), sep)
+}
+
+/// 2次元のグリッドを、各行の要素を区切り文字なしで連結し、行を改行で連結した文字列にする。
+///
+/// # 例
+/// ```
+/// let grid = vec![vec!['#', '.'], vec!['.', '#']];
+/// assert_eq!(grid_to_string(&grid), "#.\n.#");
+/// ```
+fn grid_to_string<T: std::fmt::Display>(grid: &[Vec<T>]) -> String {
+    grid.iter()
+        .map(|row| join_with(row, ""))
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+/// 浮動小数点数を小数点以下 `decimals` 桁に固定した文字列にする。
+/// 既定の `{}` による出力は指数表記や桁不足になりうるため、誤差判定のある問題ではこちらを使う。
+///
+/// # 例
+/// ```
+/// assert_eq!(float_to_string(1.0 / 3.0, 5), "0.33333");
+/// ```
+fn float_to_string(x: f64, decimals: usize) -> String {
+    format!("{:.*}", decimals, x)
+}
+
+/***********************************************************
+* String
+************************************************************/
+/// 与えられたイテレータの各要素を文字列に変換し、
+/// 指定された区切り文字で連結した1つの文字列を返す。
+///
+/// # 例
+/// ```
+/// let v = vec![1, 2, 3];
+/// let joined = join_with(v, " ");
+/// assert_eq!(joined, "1 2 3");
+/// ```
+fn join_with<I>(iter: I, sep: &str) -> String
+where
+    I: IntoIterator,
+    I::Item: std::fmt::Display,
+{
+    iter.into_iter()
+        .map(|item| item.to_string())
+        .collect::<Vec<String>>()
+        .join(sep)
+}
+
+/// 文字種 `charset`（`LOWERCASE`、`UPPERCASE`、`DIGITS` など）の中で `c` が何番目かを返す。
+/// 含まれない場合は `None` 。
+///
+/// # 例
+///
+/// ```
+/// assert_eq!(charset_index(LOWERCASE, 'c'), Some(2));
+/// assert_eq!(charset_index(DIGITS, 'a'), None);
+/// ```
+fn charset_index(charset: &str, c: char) -> Option<usize> {
+    charset.chars().position(|x| x == c)
+}
+
+/// 文字種 `charset` の `i` 番目の文字を返す。
+///
+/// # 例
+///
+/// ```
+/// assert_eq!(charset_char(UPPERCASE, 25), 'Z');
+/// ```
+fn charset_char(charset: &str, i: usize) -> char {
+    charset.chars().nth(i).unwrap()
+}
+
+/// 英小文字 `c` を `'a'` を0とする番号に変換する。
+fn lower_index(c: char) -> usize {
+    debug_assert!(c.is_ascii_lowercase());
+    (c as u8 - b'a') as usize
+}
+
+/// 番号 `i` (0..26) を英小文字に変換する。
+fn lower_char(i: usize) -> char {
+    LOWERCASE.as_bytes()[i] as char
+}
+
+/// 英大文字 `c` を `'A'` を0とする番号に変換する。
+fn upper_index(c: char) -> usize {
+    debug_assert!(c.is_ascii_uppercase());
+    (c as u8 - b'A') as usize
+}
+
+/// 番号 `i` (0..26) を英大文字に変換する。
+fn upper_char(i: usize) -> char {
+    UPPERCASE.as_bytes()[i] as char
+}
+
+/// 数字 `c` を数値に変換する。
+fn digit_index(c: char) -> usize {
+    debug_assert!(c.is_ascii_digit());
+    (c as u8 - b'0') as usize
+}
+
+/// 数値 `i` (0..10) を数字に変換する。
+fn digit_char(i: usize) -> char {
+    DIGITS.as_bytes()[i] as char
+}
+
+/// 次の出現位置のテーブル
+///
+/// `next[i][c]` は `i` 以降で文字種 `charset` の `c` 番目の文字が最初に現れる位置
+/// （現れない場合は `s.len()`）。テーブルの大きさは `(s.len() + 1) × charset の文字数`。
+/// 部分列判定や、部分列の数え上げ DP に使う。
+///
+/// # 例
+///
+/// ```
+/// let s = "abcab".chars().collect::<Vec<_>>();
+/// let next = next_occurrence_table(&s, LOWERCASE);
+/// assert_eq!(next[1][lower_index('a')], 3);
+/// assert_eq!(next[4][lower_index('a')], 5);
+/// ```
+fn next_occurrence_table(s: &[char], charset: &str) -> Vec<Vec<usize>> {
+    let sigma = charset.chars().count();
+    let n = s.len();
+    let mut next = vec![vec![n; sigma]; n + 1];
+    for i in (0..n).rev() {
+        next[i] = next[i + 1].clone();
+        if let Some(c) = charset_index(charset, s[i]) {
+            next[i][c] = i;
+        }
+    }
+    next
+}
+
+/// 文字ごとの累積個数
+///
+/// `cnt[i][c]` は `s[..i]` に含まれる文字種 `charset` の `c` 番目の文字の個数。
+/// `s[l..r]` に含まれる個数は `cnt[r][c] - cnt[l][c]` で求まる。
+///
+/// # 例
+///
+/// ```
+/// let s = "abcab".chars().collect::<Vec<_>>();
+/// let cnt = char_prefix_counts(&s, LOWERCASE);
+/// assert_eq!(cnt[5][lower_index('b')] - cnt[2][lower_index('b')], 1);
+/// ```
+fn char_prefix_counts(s: &[char], charset: &str) -> Vec<Vec<usize>> {
+    let sigma = charset.chars().count();
+    let mut cnt = vec![vec![0; sigma]; s.len() + 1];
+    for (i, &x) in s.iter().enumerate() {
+        cnt[i + 1] = cnt[i].clone();
+        if let Some(c) = charset_index(charset, x) {
+            cnt[i + 1][c] += 1;
+        }
+    }
+    cnt
+}
+
+/// 英大文字を英小文字に変換する。それ以外の文字はそのまま。
+fn to_lowercase_chars(s: &[char]) -> Vec<char> {
+    s.iter().map(|c| c.to_ascii_lowercase()).collect()
+}
+
+/// 英小文字を英大文字に変換する。それ以外の文字はそのまま。
+fn to_uppercase_chars(s: &[char]) -> Vec<char> {
+    s.iter().map(|c| c.to_ascii_uppercase()).collect()
+}
+
+/// 英大文字と英小文字を入れ替える。それ以外の文字はそのまま。
+fn swap_case_chars(s: &[char]) -> Vec<char> {
+    s.iter()
+        .map(|c| {
+            if c.is_ascii_lowercase() {
+                c.to_ascii_uppercase()
+            } else {
+                c.to_ascii_lowercase()
+            }
+        })
+        .collect()
+}
+
+/// シーザー暗号
+///
+/// 英小文字・英大文字・数字をそれぞれの文字種の中で `k` だけ巡回シフトする（`k` は負でもよい）。
+/// それ以外の文字はそのまま。
+///
+/// # 例
+///
+/// ```
+/// let s = "xyZ9-".chars().collect::<Vec<_>>();
+/// assert_eq!(caesar_shift(&s, 3), "abC2-".chars().collect::<Vec<_>>());
+/// ```
+fn caesar_shift(s: &[char], k: i64) -> Vec<char> {
+    s.iter()
+        .map(|&c| {
+            for charset in [LOWERCASE, UPPERCASE, DIGITS] {
+                if let Some(i) = charset_index(charset, c) {
+                    let m = charset.len() as i64;
+                    return charset_char(charset, (i as i64 + k).rem_euclid(m) as usize);
+                }
+            }
+            c
+        })
+        .collect()
+}
+
+/// 接頭辞関数（KMP の失敗関数）
+///
+/// `pi[i]` は `s[..=i]` の真の接頭辞かつ接尾辞であるような文字列の最大長。計算量は O(N)。
+///
+/// # 例
+///
+/// ```
+/// let s = "abacaba".chars().collect::<Vec<_>>();
+/// assert_eq!(prefix_function(&s), vec![0, 0, 1, 0, 1, 2, 3]);
+/// ```
+fn prefix_function<T: Eq>(s: &[T]) -> Vec<usize> {
+    let n = s.len();
+    let mut pi = vec![0; n];
+    for i in 1..n {
+        let mut k = pi[i - 1];
+        while k > 0 && s[i] != s[k] {
+            k = pi[k - 1];
+        }
+        if s[i] == s[k] {
+            k += 1;
+        }
+        pi[i] = k;
+    }
+    pi
+}
+
+/// KMP 法による文字列検索
+///
+/// `text` の中で `pattern` が出現する開始位置をすべて昇順に返す。計算量は O(N + M)。
+///
+/// # 例
+///
+/// ```
+/// let t = "abababa".chars().collect::<Vec<_>>();
+/// let p = "aba".chars().collect::<Vec<_>>();
+/// assert_eq!(kmp_search(&t, &p), vec![0, 2, 4]);
+/// ```
+fn kmp_search<T: Eq>(text: &[T], pattern: &[T]) -> Vec<usize> {
+    let m = pattern.len();
+    if m == 0 {
+        return (0..=text.len()).collect();
+    }
+    let pi = prefix_function(pattern);
+    let mut res = Vec::new();
+    let mut k = 0;
+    for (i, c) in text.iter().enumerate() {
+        while k > 0 && *c != pattern[k] {
+            k = pi[k - 1];
+        }
+        if *c == pattern[k] {
+            k += 1;
+        }
+        if k == m {
+            res.push(i + 1 - m);
+            k = pi[k - 1];
+        }
+    }
+    res
+}
+
+/// Z-algorithm
+///
+/// `z[i]` は `s` と `s[i..]` の最長共通接頭辞の長さ（`z[0] == s.len()`）。
+/// `&[char]` や `&[u8]`、`&[i64]` など任意の比較可能なスライスに使える。計算量は O(N)。
+///
+/// # 例
+///
+/// ```
+/// let s = "aabcaab".chars().collect::<Vec<_>>();
+/// assert_eq!(z_function(&s), vec![7, 1, 0, 0, 3, 1, 0]);
+/// ```
+fn z_function<T: Eq>(s: &[T]) -> Vec<usize> {
+    let n = s.len();
+    let mut z = vec![0; n];
+    if n == 0 {
+        return z;
+    }
+    z[0] = n;
+    let (mut l, mut r) = (0, 0);
+    for i in 1..n {
+        if i < r {
+            z[i] = (r - i).min(z[i - l]);
+        }
+        while i + z[i] < n && s[z[i]] == s[i + z[i]] {
+            z[i] += 1;
+        }
+        if i + z[i] > r {
+            l = i;
+            r = i + z[i];
+        }
+    }
+    z
+}
+
+/// Manacher のアルゴリズム
+///
+/// 各位置を中心とする最長回文の半径を O(N) で求める。
+///
+/// # 戻り値
+///
+/// `(odd, even)` の組。
+/// * `odd[i]` - `s[i + 1 - k..i + k]` が回文となる最大の `k`（長さ `2k - 1` の奇数長回文）。
+/// * `even[i]` - `s[i - k..i + k]` が回文となる最大の `k`（`s[i - 1]` と `s[i]` の間を中心とする長さ `2k` の偶数長回文）。
+///
+/// # 例
+///
+/// ```
+/// let s = "abaab".chars().collect::<Vec<_>>();
+/// let (odd, even) = manacher(&s);
+/// assert_eq!(odd, vec![1, 2, 1, 1, 1]);
+/// assert_eq!(even, vec![0, 0, 0, 2, 0]);
+/// ```
+fn manacher<T: Eq>(s: &[T]) -> (Vec<usize>, Vec<usize>) {
+    let n = s.len();
+    let mut odd = vec![0; n];
+    let (mut l, mut r) = (0, 0);
+    for i in 0..n {
+        let mut k = if i < r { odd[l + r - 1 - i].min(r - i) } else { 1 };
+        while i + k < n && i >= k && s[i - k] == s[i + k] {
+            k += 1;
+        }
+        odd[i] = k;
+        if i + k > r {
+            l = i + 1 - k;
+            r = i + k;
+        }
+    }
+    let mut even = vec![0; n];
+    let (mut l, mut r) = (0, 0);
+    for i in 0..n {
+        let mut k = if i < r { even[l + r - i].min(r - i) } else { 0 };
+        while i + k < n && i > k && s[i - k - 1] == s[i + k] {
+            k += 1;
+        }
+        even[i] = k;
+        if i + k > r {
+            l = i - k;
+            r = i + k;
+        }
+    }
+    (odd, even)
+}
+
+/// 最長回文部分文字列の半開区間 `[l, r)` を返す。複数ある場合は最も左のもの。
+///
+/// # 例
+///
+/// ```
+/// let s = "xabaabz".chars().collect::<Vec<_>>();
+/// assert_eq!(longest_palindrome(&s), (2, 6));
+/// ```
+fn longest_palindrome<T: Eq>(s: &[T]) -> (usize, usize) {
+    let (odd, even) = manacher(s);
+    let mut best = (0, 0);
+    for i in 0..s.len() {
+        let (l, r) = (i + 1 - odd[i], i + odd[i]);
+        if r - l > best.1 - best.0 || (r - l == best.1 - best.0 && l < best.0) {
+            best = (l, r);
+        }
+        let (l, r) = (i - even[i], i + even[i]);
+        if r - l > best.1 - best.0 || (r - l == best.1 - best.0 && l < best.0) {
+            best = (l, r);
+        }
+    }
+    best
+}
+
+/// Lyndon 分解（Duval のアルゴリズム）
+///
+/// `s` を辞書順で非増加な Lyndon 文字列の列 `s = w1 w2 ... wk` に分解し、
+/// 各 `wi` を `s` 上の半開区間として返す。計算量は O(N)。
+///
+/// # 例
+///
+/// ```
+/// let s = "abaab".chars().collect::<Vec<_>>();
+/// assert_eq!(lyndon_factorization(&s), vec![(0, 2), (2, 5)]); // ab, aab
+/// ```
+fn lyndon_factorization<T: Ord>(s: &[T]) -> Vec<(usize, usize)> {
+    let n = s.len();
+    let mut res = Vec::new();
+    let mut i = 0;
+    while i < n {
+        let (mut j, mut k) = (i + 1, i);
+        while j < n && s[k] <= s[j] {
+            if s[k] < s[j] {
+                k = i;
+            } else {
+                k += 1;
+            }
+            j += 1;
+        }
+        while i <= k {
+            res.push((i, i + j - k));
+            i += j - k;
+        }
+    }
+    res
+}
+
+/// 辞書順最小の巡回シフト（Booth のアルゴリズム）
+///
+/// `s[k..] + s[..k]` が辞書順最小となる `k` のうち最小のものを返す。計算量は O(N)。
+///
+/// # 例
+///
+/// ```
+/// let s = "bcaab".chars().collect::<Vec<_>>();
+/// assert_eq!(min_rotation(&s), 2); // "aabbc"
+/// ```
+fn min_rotation<T: Ord>(s: &[T]) -> usize {
+    let n = s.len();
+    if n == 0 {
+        return 0;
+    }
+    let mut f = vec![usize::MAX; 2 * n];
+    let mut k = 0;
+    for j in 1..2 * n {
+        let c = &s[j % n];
+        let mut i = f[j - k - 1];
+        while i != usize::MAX && *c != s[(k + i + 1) % n] {
+            if *c < s[(k + i + 1) % n] {
+                k = j - i - 1;
+            }
+            i = f[i];
+        }
+        if i == usize::MAX && *c != s[k % n] {
+            if *c < s[k % n] {
+                k = j;
+            }
+            f[j - k] = usize::MAX;
+        } else {
+            f[j - k] = i.wrapping_add(1);
+        }
+    }
+    k % n
+}
+
+/// 最小周期
+///
+/// 全ての `i` で `s[i] == s[i + p]` となる最小の `p >= 1` を、接頭辞関数を用いて O(N) で求める。
+/// `s.len() % p == 0` のとき、`s` は `s[..p]` の繰り返しになっている。
+///
+/// # 例
+///
+/// ```
+/// let s = "abcabcab".chars().collect::<Vec<_>>();
+/// assert_eq!(minimal_period(&s), 3);
+/// ```
+fn minimal_period<T: Eq>(s: &[T]) -> usize {
+    match prefix_function(s).last() {
+        Some(&p) => s.len() - p,
+        None => 0,
+    }
+}
+
+/// `s` が `t` を1回以上繰り返した文字列であるかどうかを返す。
+///
+/// # 例
+///
+/// ```
+/// let s = "ababab".chars().collect::<Vec<_>>();
+/// assert!(is_power_of(&s, &['a', 'b']));
+/// ```
+fn is_power_of<T: Eq>(s: &[T], t: &[T]) -> bool {
+    if t.is_empty() {
+        return s.is_empty();
+    }
+    s.len() % t.len() == 0 && s.chunks(t.len()).all(|c| c == t)
+}
+
+/// `t` が `s` の巡回シフトであるかどうかを返す。計算量は O(N)。
+///
+/// # 例
+///
+/// ```
+/// let s = "abcd".chars().collect::<Vec<_>>();
+/// let t = "cdab".chars().collect::<Vec<_>>();
+/// assert!(is_rotation_of(&s, &t));
+/// ```
+fn is_rotation_of<T: Eq + Clone>(s: &[T], t: &[T]) -> bool {
+    if s.len() != t.len() {
+        return false;
+    }
+    if s.is_empty() {
+        return true;
+    }
+    let ss = [s, s].concat();
+    !kmp_search(&ss, t).is_empty()
+}
+
+/***********************************************************
+* Aho-Corasick
+************************************************************/
+/// Aho-Corasick 法のオートマトン
+///
+/// 複数のパターンを同時に検索する。文字種は `LOWERCASE` などの文字列で指定し、
+/// 遷移は全て前計算される（goto 表）ため、`next` は O(1) で動作する。
+/// ノード0が根（空文字列）。ノードは BFS 順に番号が付く。
+///
+/// # 例
+///
+/// ```
+/// let patterns = vec!["he", "she", "his", "hers"]
+///     .into_iter()
+///     .map(|p| p.chars().collect::<Vec<_>>())
+///     .collect::<Vec<_>>();
+/// let ac = AhoCorasick::new(LOWERCASE, &patterns);
+/// let text = "ushers".chars().collect::<Vec<_>>();
+/// assert_eq!(ac.count_matches(&text), vec![1, 1, 0, 1]);
+///
+/// // パターンを1つも含まない長さ L の文字列の個数を数える DP
+/// let mut dp = vec![0_i64; ac.len()];
+/// dp[0] = 1;
+/// for _ in 0..L {
+///     let mut ndp = vec![0; ac.len()];
+///     for v in 0..ac.len() {
+///         for c in 0..ac.sigma() {
+///             let u = ac.next_index(v, c);
+///             if ac.match_count(u) == 0 {
+///                 ndp[u] += dp[v];
+///             }
+///         }
+///     }
+///     dp = ndp;
+/// }
+/// ```
+#[derive(Clone, Debug)]
+struct AhoCorasick {
+    code: Vec<usize>,
+    sigma: usize,
+    next: Vec<Vec<usize>>,
+    fail: Vec<usize>,
+    ends: Vec<Vec<usize>>,
+    output: Vec<Option<usize>>,
+    count: Vec<usize>,
+    pattern_nodes: Vec<usize>,
+}
+
+impl AhoCorasick {
+    /// 文字種 `alphabet`（`LOWERCASE`、`UPPERCASE`、`DIGITS` やそれらを連結したもの）と
+    /// パターンの列からオートマトンを構築する。計算量は O(Σ|P| × 文字種数)。
+    fn new(alphabet: &str, patterns: &[Vec<char>]) -> Self {
+        let mut code = vec![usize::MAX; 128];
+        let mut sigma = 0;
+        for c in alphabet.chars() {
+            assert!(c.is_ascii());
+            code[c as usize] = sigma;
+            sigma += 1;
+        }
+        let mut next = vec![vec![usize::MAX; sigma]];
+        let mut ends = vec![Vec::new()];
+        let mut pattern_nodes = Vec::with_capacity(patterns.len());
+        for (id, p) in patterns.iter().enumerate() {
+            let mut v = 0;
+            for &c in p {
+                let c = Self::code_of(&code, c);
+                if next[v][c] == usize::MAX {
+                    next[v][c] = next.len();
+                    next.push(vec![usize::MAX; sigma]);
+                    ends.push(Vec::new());
+                }
+                v = next[v][c];
+            }
+            ends[v].push(id);
+            pattern_nodes.push(v);
+        }
+
+        let n = next.len();
+        let mut fail = vec![0; n];
+        let mut output = vec![None; n];
+        let mut count = vec![0; n];
+        let mut order = Vec::with_capacity(n);
+        count[0] = ends[0].len();
+        for u in next[0].iter_mut() {
+            if *u == usize::MAX {
+                *u = 0;
+            } else {
+                order.push(*u);
+            }
+        }
+        let mut head = 0;
+        while head < order.len() {
+            let v = order[head];
+            head += 1;
+            let f = fail[v];
+            output[v] = if ends[f].is_empty() { output[f] } else { Some(f) };
+            count[v] = ends[v].len() + count[f];
+            // f は v より浅いノードだが、番号の大小は挿入順によるのでどちらもありうる
+            let (next_v, next_f) = if f < v {
+                let (lo, hi) = next.split_at_mut(v);
+                (&mut hi[0], &lo[f])
+            } else {
+                let (lo, hi) = next.split_at_mut(f);
+                (&mut lo[v], &hi[0])
+            };
+            for (u, &g) in next_v.iter_mut().zip(next_f.iter()) {
+                if *u == usize::MAX {
+                    *u = g;
+                } else {
+                    fail[*u] = g;
+                    order.push(*u);
+                }
+            }
+        }
+
+        // ノード番号を BFS 順に振り直す
+        let mut new_id = vec![0; n];
+        for (i, &v) in order.iter().enumerate() {
+            new_id[v] = i + 1;
+        }
+        let mut perm = vec![0];
+        perm.extend(order);
+        AhoCorasick {
+            code,
+            sigma,
+            next: perm
+                .iter()
+                .map(|&v| next[v].iter().map(|&u| new_id[u]).collect())
+                .collect(),
+            fail: perm.iter().map(|&v| new_id[fail[v]]).collect(),
+            ends: perm.iter().map(|&v| ends[v].clone()).collect(),
+            output: perm.iter().map(|&v| output[v].map(|u| new_id[u])).collect(),
+            count: perm.iter().map(|&v| count[v]).collect(),
+            pattern_nodes: pattern_nodes.iter().map(|&v| new_id[v]).collect(),
+        }
+    }
+
+    /// ノード数を返す。
+    fn len(&self) -> usize {
+        self.next.len()
+    }
+
+    /// 文字種の数を返す。
+    fn sigma(&self) -> usize {
+        self.sigma
+    }
+
+    /// 文字 `c` の文字種内での番号を返す。
+    fn index_of(&self, c: char) -> usize {
+        Self::code_of(&self.code, c)
+    }
+
+    fn code_of(code: &[usize], c: char) -> usize {
+        let i = code.get(c as usize).copied().unwrap_or(usize::MAX);
+        assert!(i != usize::MAX, "{} is not in the alphabet", c);
+        i
+    }
+
+    /// ノード `v` から文字 `c` で遷移した先のノードを返す。
+    fn next(&self, v: usize, c: char) -> usize {
+        self.next[v][self.index_of(c)]
+    }
+
+    /// ノード `v` から文字種内で `c` 番目の文字で遷移した先のノードを返す。
+    fn next_index(&self, v: usize, c: usize) -> usize {
+        self.next[v][c]
+    }
+
+    /// ノード `v` の失敗リンク（suffix link）を返す。
+    fn fail(&self, v: usize) -> usize {
+        self.fail[v]
+    }
+
+    /// ノード `v` に到達したときに末尾で一致するパターンの個数（重複を含む）を返す。
+    fn match_count(&self, v: usize) -> usize {
+        self.count[v]
+    }
+
+    /// ノード `v` に到達したときに末尾で一致するパターンの番号を、長い順に列挙する。
+    fn matches(&self, v: usize) -> impl Iterator<Item = usize> + '_ {
+        let first = if self.ends[v].is_empty() { self.output[v] } else { Some(v) };
+        std::iter::successors(first, move |&u| self.output[u])
+            .flat_map(move |u| self.ends[u].iter().copied())
+    }
+
+    /// パターン `id` の末尾に対応するノードを返す。
+    fn pattern_node(&self, id: usize) -> usize {
+        self.pattern_nodes[id]
+    }
+
+    /// `text` の中での各パターンの出現回数を返す。計算量は O(|text| + ノード数)。
+    fn count_matches(&self, text: &[char]) -> Vec<usize> {
+        let mut visited = vec![0; self.len()];
+        let mut v = 0;
+        for &c in text {
+            v = self.next(v, c);
+            visited[v] += 1;
+        }
+        for v in (1..self.len()).rev() {
+            visited[self.fail[v]] += visited[v];
+        }
+        self.pattern_nodes.iter().map(|&v| visited[v]).collect()
+    }
+}
+
+/***********************************************************
+* Trie
+************************************************************/
+/// 重複を許す文字列の集合を管理するトライ木
+///
+/// 各操作の計算量は文字列の長さを L として O(L log σ)。
+///
+/// # 例
+///
+/// ```
+/// let mut trie = Trie::new();
+/// for w in ["apple", "app", "banana", "app"] {
+///     trie.insert(&w.chars().collect::<Vec<_>>());
+/// }
+/// assert_eq!(trie.count_prefix(&['a', 'p']), 3);
+/// assert_eq!(trie.kth(2), Some("apple".chars().collect()));
+/// ```
+#[derive(Clone, Debug)]
+struct Trie {
+    children: Vec<BTreeMap<char, usize>>,
+    pass: Vec<usize>,
+    end: Vec<usize>,
+}
+
+impl Trie {
+    fn new() -> Self {
+        Trie {
+            children: vec![BTreeMap::new()],
+            pass: vec![0],
+            end: vec![0],
+        }
+    }
+
+    /// 格納されている文字列の個数（重複を含む）を返す。
+    fn len(&self) -> usize {
+        self.pass[0]
+    }
+
+    fn find(&self, word: &[char]) -> Option<usize> {
+        let mut v = 0;
+        for c in word {
+            v = *self.children[v].get(c)?;
+        }
+        Some(v)
+    }
+
+    /// `word` を1つ追加する。
+    fn insert(&mut self, word: &[char]) {
+        let mut v = 0;
+        self.pass[0] += 1;
+        for &c in word {
+            v = match self.children[v].get(&c) {
+                Some(&u) => u,
+                None => {
+                    let u = self.children.len();
+                    self.children.push(BTreeMap::new());
+                    self.pass.push(0);
+                    self.end.push(0);
+                    self.children[v].insert(c, u);
+                    u
+                }
+            };
+            self.pass[v] += 1;
+        }
+        self.end[v] += 1;
+    }
+
+    /// `word` を1つ削除する。`word` が含まれていなかった場合は `false` を返す。
+    fn remove(&mut self, word: &[char]) -> bool {
+        match self.find(word) {
+            Some(v) if self.end[v] > 0 => {
+                self.end[v] -= 1;
+                let mut v = 0;
+                self.pass[0] -= 1;
+                for c in word {
+                    v = self.children[v][c];
+                    self.pass[v] -= 1;
+                }
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// `word` がいくつ含まれているかを返す。
+    fn count(&self, word: &[char]) -> usize {
+        self.find(word).map_or(0, |v| self.end[v])
+    }
+
+    /// `prefix` で始まる文字列がいくつ含まれているかを返す。
+    fn count_prefix(&self, prefix: &[char]) -> usize {
+        self.find(prefix).map_or(0, |v| self.pass[v])
+    }
+
+    /// 辞書順で `k` 番目（0始まり、重複を含む）の文字列を返す。
+    fn kth(&self, mut k: usize) -> Option<Vec<char>> {
+        if k >= self.len() {
+            return None;
+        }
+        let mut v = 0;
+        let mut res = Vec::new();
+        loop {
+            if k < self.end[v] {
+                return Some(res);
+            }
+            k -= self.end[v];
+            for (&c, &u) in &self.children[v] {
+                if k < self.pass[u] {
+                    res.push(c);
+                    v = u;
+                    break;
+                }
+                k -= self.pass[u];
+            }
+        }
+    }
+}
+
+/// 重複を許す非負整数の集合を管理する二分トライ木
+///
+/// 値は `bits` ビット以下であるものとする。各操作の計算量は O(bits)。
+///
+/// # 例
+///
+/// ```
+/// let mut bt = BinaryTrie::new(30);
+/// for x in [3, 10, 5, 10] {
+///     bt.insert(x);
+/// }
+/// assert_eq!(bt.max_xor(6), Some(12)); // 6 ^ 10
+/// assert_eq!(bt.min_xor(6), Some(3)); // 6 ^ 5
+/// assert_eq!(bt.kth_smallest(2), Some(10));
+/// ```
+#[derive(Clone, Debug)]
+struct BinaryTrie {
+    bits: u32,
+    children: Vec<[usize; 2]>,
+    count: Vec<usize>,
+}
+
+impl BinaryTrie {
+    fn new(bits: u32) -> Self {
+        assert!(bits <= 64);
+        BinaryTrie {
+            bits,
+            children: vec![[0; 2]],
+            count: vec![0],
+        }
+    }
+
+    /// 格納されている値の個数（重複を含む）を返す。
+    fn len(&self) -> usize {
+        self.count[0]
+    }
+
+    fn bit(&self, x: u64, i: u32) -> usize {
+        ((x >> i) & 1) as usize
+    }
+
+    /// `x` を1つ追加する。
+    fn insert(&mut self, x: u64) {
+        let mut v = 0;
+        self.count[0] += 1;
+        for i in (0..self.bits).rev() {
+            let b = self.bit(x, i);
+            if self.children[v][b] == 0 {
+                self.children[v][b] = self.children.len();
+                self.children.push([0; 2]);
+                self.count.push(0);
+            }
+            v = self.children[v][b];
+            self.count[v] += 1;
+        }
+    }
+
+    /// `x` を1つ削除する。`x` が含まれていなかった場合は `false` を返す。
+    fn remove(&mut self, x: u64) -> bool {
+        if self.count(x) == 0 {
+            return false;
+        }
+        let mut v = 0;
+        self.count[0] -= 1;
+        for i in (0..self.bits).rev() {
+            v = self.children[v][self.bit(x, i)];
+            self.count[v] -= 1;
+        }
+        true
+    }
+
+    /// `x` がいくつ含まれているかを返す。
+    fn count(&self, x: u64) -> usize {
+        let mut v = 0;
+        for i in (0..self.bits).rev() {
+            v = self.children[v][self.bit(x, i)];
+            if v == 0 {
+                return 0;
+            }
+        }
+        self.count[v]
+    }
+
+    /// 集合内の値 `y` についての `x ^ y` の最小値を返す。
+    fn min_xor(&self, x: u64) -> Option<u64> {
+        self.xor_search(x, false)
+    }
+
+    /// 集合内の値 `y` についての `x ^ y` の最大値を返す。
+    fn max_xor(&self, x: u64) -> Option<u64> {
+        self.xor_search(x, true)
+    }
+
+    fn xor_search(&self, x: u64, maximize: bool) -> Option<u64> {
+        if self.len() == 0 {
+            return None;
+        }
+        let mut v = 0;
+        let mut res = 0;
+        for i in (0..self.bits).rev() {
+            let want = self.bit(x, i) ^ maximize as usize;
+            let u = self.children[v][want];
+            if u != 0 && self.count[u] > 0 {
+                v = u;
+                res |= (maximize as u64) << i;
+            } else {
+                v = self.children[v][want ^ 1];
+                res |= (!maximize as u64) << i;
+            }
+        }
+        Some(res)
+    }
+
+    /// 小さい方から `k` 番目（0始まり、重複を含む）の値を返す。
+    fn kth_smallest(&self, mut k: usize) -> Option<u64> {
+        if k >= self.len() {
+            return None;
+        }
+        let mut v = 0;
+        let mut res = 0;
+        for i in (0..self.bits).rev() {
+            let l = self.children[v][0];
+            let cnt = if l == 0 { 0 } else { self.count[l] };
+            if k < cnt {
+                v = l;
+            } else {
+                k -= cnt;
+                v = self.children[v][1];
+                res |= 1 << i;
+            }
+        }
+        Some(res)
+    }
+
+    /// `x` 未満の値の個数を返す。
+    fn count_less(&self, x: u64) -> usize {
+        let mut v = 0;
+        let mut res = 0;
+        for i in (0..self.bits).rev() {
+            let b = self.bit(x, i);
+            if b == 1 && self.children[v][0] != 0 {
+                res += self.count[self.children[v][0]];
+            }
+            v = self.children[v][b];
+            if v == 0 {
+                break;
+            }
+        }
+        res
+    }
+}
+
+/***********************************************************
+* Suffix Automaton / Suffix Array
+************************************************************/
+/// 接尾辞オートマトン
+///
+/// 文字列 `s` の全ての部分文字列を受理する最小の DFA。構築は O(N log σ)。
+/// ノード0が空文字列に対応する初期状態。
+///
+/// # 例
+///
+/// ```
+/// let s = "abab".chars().collect::<Vec<_>>();
+/// let sam = SuffixAutomaton::new(&s);
+/// assert_eq!(sam.distinct_substrings(), 7);
+/// assert_eq!(sam.occurrences(&['a', 'b']), 2);
+/// assert_eq!(sam.kth_substring(1), Some(vec!['a', 'b']));
+/// ```
+#[derive(Clone, Debug)]
+struct SuffixAutomaton {
+    next: Vec<BTreeMap<char, usize>>,
+    link: Vec<Option<usize>>,
+    len: Vec<usize>,
+    cnt: Vec<usize>,
+    paths: Vec<usize>,
+}
+
+impl SuffixAutomaton {
+    fn new(s: &[char]) -> Self {
+        let mut sam = SuffixAutomaton {
+            next: vec![BTreeMap::new()],
+            link: vec![None],
+            len: vec![0],
+            cnt: vec![0],
+            paths: Vec::new(),
+        };
+        let mut last = 0;
+        for &c in s {
+            last = sam.extend(last, c);
+        }
+
+        // len の降順（トポロジカル順の逆）に出現回数と経路数を集計する
+        let n = sam.len.len();
+        let mut order = (0..n).collect::<Vec<_>>();
+        order.sort_by_key(|&v| std::cmp::Reverse(sam.len[v]));
+        sam.paths = vec![1; n];
+        for &v in &order {
+            if let Some(p) = sam.link[v] {
+                sam.cnt[p] += sam.cnt[v];
+            }
+            for &u in sam.next[v].values() {
+                sam.paths[v] += sam.paths[u];
+            }
+        }
+        sam
+    }
+
+    fn extend(&mut self, last: usize, c: char) -> usize {
+        let cur = self.add_node(self.len[last] + 1, 1);
+        let mut p = Some(last);
+        while let Some(v) = p {
+            if self.next[v].contains_key(&c) {
+                break;
+            }
+            self.next[v].insert(c, cur);
+            p = self.link[v];
+        }
+        match p {
+            None => self.link[cur] = Some(0),
+            Some(v) => {
+                let q = self.next[v][&c];
+                if self.len[v] + 1 == self.len[q] {
+                    self.link[cur] = Some(q);
+                } else {
+                    let clone = self.add_node(self.len[v] + 1, 0);
+                    self.next[clone] = self.next[q].clone();
+                    self.link[clone] = self.link[q];
+                    let mut p = Some(v);
+                    while let Some(w) = p {
+                        if self.next[w].get(&c) != Some(&q) {
+                            break;
+                        }
+                        self.next[w].insert(c, clone);
+                        p = self.link[w];
+                    }
+                    self.link[q] = Some(clone);
+                    self.link[cur] = Some(clone);
+                }
+            }
+        }
+        cur
+    }
+
+    fn add_node(&mut self, len: usize, cnt: usize) -> usize {
+        self.next.push(BTreeMap::new());
+        self.link.push(None);
+        self.len.push(len);
+        self.cnt.push(cnt);
+        self.len.len() - 1
+    }
+
+    /// `t` を読んだ後の状態を返す。`t` が部分文字列でなければ `None` 。
+    fn state(&self, t: &[char]) -> Option<usize> {
+        let mut v = 0;
+        for c in t {
+            v = *self.next[v].get(c)?;
+        }
+        Some(v)
+    }
+
+    /// `t` が部分文字列かどうかを返す。
+    fn contains(&self, t: &[char]) -> bool {
+        self.state(t).is_some()
+    }
+
+    /// `t` が部分文字列として何回出現するかを返す。
+    fn occurrences(&self, t: &[char]) -> usize {
+        if t.is_empty() {
+            return self.len.iter().max().unwrap() + 1;
+        }
+        self.state(t).map_or(0, |v| self.cnt[v])
+    }
+
+    /// 空でない相異なる部分文字列の個数を返す。
+    fn distinct_substrings(&self) -> usize {
+        (1..self.len.len())
+            .map(|v| self.len[v] - self.len[self.link[v].unwrap()])
+            .sum()
+    }
+
+    /// 空でない相異なる部分文字列のうち、辞書順で `k` 番目（0始まり）のものを返す。
+    fn kth_substring(&self, mut k: usize) -> Option<Vec<char>> {
+        if k >= self.paths[0] - 1 {
+            return None;
+        }
+        let mut v = 0;
+        let mut res = Vec::new();
+        loop {
+            for (&c, &u) in &self.next[v] {
+                if k < self.paths[u] {
+                    res.push(c);
+                    v = u;
+                    break;
+                }
+                k -= self.paths[u];
+            }
+            if k == 0 {
+                return Some(res);
+            }
+            k -= 1;
+        }
+    }
+
+    /// `s` と `t` の最長共通部分文字列を、`t` 上の半開区間 `[l, r)` として返す。
+    fn longest_common_substring(&self, t: &[char]) -> (usize, usize) {
+        let mut v = 0;
+        let mut l = 0;
+        let mut best = (0, 0);
+        for (i, c) in t.iter().enumerate() {
+            while v != 0 && !self.next[v].contains_key(c) {
+                v = self.link[v].unwrap();
+                l = self.len[v];
+            }
+            if let Some(&u) = self.next[v].get(c) {
+                v = u;
+                l += 1;
+            }
+            if l > best.1 - best.0 {
+                best = (i + 1 - l, i + 1);
+            }
+        }
+        best
+    }
+}
+
+/// 接尾辞配列と LCP 配列のラッパー
+///
+/// ac-library の `suffix_array_arbitrary` / `lcp_array_arbitrary` を用いて構築し、
+/// LCP 配列に対するスパーステーブルで任意の2つの接尾辞の最長共通接頭辞を O(1) で求める。
+/// 構築は O(N log N)。
+///
+/// # 例
+///
+/// ```
+/// let s = "abracadabra".chars().collect::<Vec<_>>();
+/// let sa = SuffixArray::new(&s);
+/// assert_eq!(sa.lcp(0, 7), 4); // "abra"
+/// ```
+#[derive(Clone, Debug)]
+struct SuffixArray {
+    sa: Vec<usize>,
+    rank: Vec<usize>,
+    table: Vec<Vec<usize>>,
+}
+
+impl SuffixArray {
+    fn new<T: Ord>(s: &[T]) -> Self {
+        let n = s.len();
+        let sa = suffix_array_arbitrary(s);
+        // ac-library の lcp_array は空列を受け付けない
+        let lcp = if n == 0 {
+            Vec::new()
+        } else {
+            lcp_array_arbitrary(s, &sa)
+        };
+        let mut rank = vec![0; n];
+        for (i, &p) in sa.iter().enumerate() {
+            rank[p] = i;
+        }
+        let mut table = vec![lcp];
+        let mut k = 1;
+        while 2 * k <= table[0].len() {
+            let prev = table.last().unwrap();
+            let row = (0..prev.len() - k)
+                .map(|i| prev[i].min(prev[i + k]))
+                .collect();
+            table.push(row);
+            k *= 2;
+        }
+        SuffixArray { sa, rank, table }
+    }
+
+    /// 接尾辞配列（辞書順で `i` 番目の接尾辞の開始位置）を返す。
+    fn sa(&self) -> &[usize] {
+        &self.sa
+    }
+
+    /// 接尾辞 `s[i..]` が辞書順で何番目かを返す。
+    fn rank(&self, i: usize) -> usize {
+        self.rank[i]
+    }
+
+    /// 隣接する接尾辞の LCP 配列（`lcp[i]` は `sa[i]` と `sa[i + 1]` の LCP）を返す。
+    fn lcp_array(&self) -> &[usize] {
+        &self.table[0]
+    }
+
+    /// 接尾辞 `s[i..]` と `s[j..]` の最長共通接頭辞の長さを返す。
+    fn lcp(&self, i: usize, j: usize) -> usize {
+        if i == j {
+            return self.sa.len() - i;
+        }
+        let (l, r) = {
+            let (a, b) = (self.rank[i], self.rank[j]);
+            (a.min(b), a.max(b))
+        };
+        let k = (usize::BITS - 1 - (r - l).leading_zeros()) as usize;
+        self.table[k][l].min(self.table[k][r - (1 << k)])
+    }
+}
+
+/***********************************************************
+* Palindromic Tree
+************************************************************/
+/// 回文木（eertree）
+///
+/// 文字列に現れる相異なる回文を全て管理する。文字はオンラインに末尾へ追加でき、全体で O(N log σ)。
+/// ノード0は長さ -1 の仮想的な根、ノード1は長さ0の空文字列の根、ノード2以降がそれぞれ1つの回文に対応する。
+///
+/// # 例
+///
+/// ```
+/// let tree = PalindromicTree::new(b"abaaba");
+/// assert_eq!(tree.distinct_palindromes(), 6); // a, b, aba, aa, baab, abaaba
+/// let occ = tree.occurrences();
+/// // "a" は長さ -1 の根の両端に 'a' を付けた回文で、4回出現する
+/// assert_eq!(occ[tree.child(0, b'a').unwrap()], 4);
+/// ```
+#[derive(Clone, Debug)]
+struct PalindromicTree {
+    s: Vec<u8>,
+    next: Vec<BTreeMap<u8, usize>>,
+    link: Vec<usize>,
+    len: Vec<isize>,
+    count: Vec<usize>,
+    last: usize,
+    suffix_nodes: Vec<usize>,
+}
+
+impl PalindromicTree {
+    fn new(s: &[u8]) -> Self {
+        let mut tree = PalindromicTree {
+            s: Vec::with_capacity(s.len()),
+            next: vec![BTreeMap::new(), BTreeMap::new()],
+            link: vec![0, 0],
+            len: vec![-1, 0],
+            count: vec![0, 0],
+            last: 1,
+            suffix_nodes: Vec::with_capacity(s.len()),
+        };
+        for &c in s {
+            tree.push(c);
+        }
+        tree
+    }
+
+    /// `v` の回文接尾辞を辿り、直前に `c` があるものを探す。
+    fn find(&self, mut v: usize, c: u8) -> usize {
+        let i = self.s.len() as isize - 1;
+        loop {
+            let j = i - 1 - self.len[v];
+            if j >= 0 && self.s[j as usize] == c {
+                return v;
+            }
+            v = self.link[v];
+        }
+    }
+
+    /// 文字 `c` を末尾に追加し、追加後の文字列の最長回文接尾辞のノードを返す。
+    fn push(&mut self, c: u8) -> usize {
+        self.s.push(c);
+        let p = self.find(self.last, c);
+        let v = match self.next[p].get(&c) {
+            Some(&v) => v,
+            None => {
+                let v = self.len.len();
+                let link = if self.len[p] == -1 {
+                    1
+                } else {
+                    let q = self.find(self.link[p], c);
+                    self.next[q][&c]
+                };
+                self.next.push(BTreeMap::new());
+                self.link.push(link);
+                self.len.push(self.len[p] + 2);
+                self.count.push(0);
+                self.next[p].insert(c, v);
+                v
+            }
+        };
+        self.count[v] += 1;
+        self.last = v;
+        self.suffix_nodes.push(v);
+        v
+    }
+
+    /// ノード数（根の2つを含む）を返す。
+    fn len(&self) -> usize {
+        self.len.len()
+    }
+
+    /// 相異なる空でない回文部分文字列の個数を返す。
+    fn distinct_palindromes(&self) -> usize {
+        self.len.len() - 2
+    }
+
+    /// ノード `v` が表す回文の長さを返す（根は -1 と 0）。
+    fn node_len(&self, v: usize) -> isize {
+        self.len[v]
+    }
+
+    /// ノード `v` の回文接尾辞リンク（`v` の真の回文接尾辞のうち最長のもの）を返す。
+    fn suffix_link(&self, v: usize) -> usize {
+        self.link[v]
+    }
+
+    /// ノード `v` の両端に文字 `c` を付けた回文のノードを返す。
+    fn child(&self, v: usize, c: u8) -> Option<usize> {
+        self.next[v].get(&c).copied()
+    }
+
+    /// `s[..=i]` の最長回文接尾辞のノードを返す。
+    fn suffix_node(&self, i: usize) -> usize {
+        self.suffix_nodes[i]
+    }
+
+    /// 各ノードの回文が文字列全体に何回出現するかを返す。計算量は O(N)。
+    fn occurrences(&self) -> Vec<usize> {
+        let mut occ = self.count.clone();
+        // 子ノードは親より後に作られるので、逆順に処理すれば接尾辞リンク先へ正しく伝播する
+        for v in (2..self.len()).rev() {
+            occ[self.link[v]] += occ[v];
+        }
+        occ[0] = 0;
+        occ[1] = 0;
+        occ
+    }
+}
+
+/***********************************************************
+* Rolling Hash
+************************************************************/
+/// `ROLLING_HASH_MOD` (2^61 - 1) を法とする乗算
+fn rolling_hash_mul(a: u64, b: u64) -> u64 {
+    let m = ROLLING_HASH_MOD as u64;
+    let t = a as u128 * b as u128;
+    let t = (t >> 61) as u64 + (t as u64 & m);
+    if t >= m {
+        t - m
+    } else {
+        t
+    }
+}
+
+/// `ROLLING_HASH_MOD` を法とする加算
+fn rolling_hash_add(a: u64, b: u64) -> u64 {
+    let m = ROLLING_HASH_MOD as u64;
+    let t = a + b;
+    if t >= m {
+        t - m
+    } else {
+        t
+    }
+}
+
+/// ローリングハッシュの基数
+///
+/// 実行ごとにランダムに決まり（hack 対策）、同一実行内では常に同じ値を返すため、
+/// 異なる文字列から作った `RollingHash` どうしでもハッシュ値を比較できる。
+fn rolling_hash_base() -> u64 {
+    use std::hash::{BuildHasher, Hasher};
+    static BASE: std::sync::OnceLock<u64> = std::sync::OnceLock::new();
+    *BASE.get_or_init(|| {
+        let mut hasher = std::collections::hash_map::RandomState::new().build_hasher();
+        hasher.write_u64(0);
+        hasher.finish() % (ROLLING_HASH_MOD as u64 - 4) + 2
+    })
+}
+
+/// ローリングハッシュ
+///
+/// 前計算 O(N) で、部分文字列のハッシュ値を O(1)、LCP を O(log N) で求める。
+/// `&[u8]` と `&[char]`（proconio の `Bytes` / `Chars`）のどちらからでも作れる。
+///
+/// # 例
+///
+/// ```
+/// let s = "abcabc".chars().collect::<Vec<_>>();
+/// let rh = RollingHash::new(&s);
+/// assert_eq!(rh.get(0, 3), rh.get(3, 6));
+/// assert_eq!(rh.lcp(0, &rh, 3), 3);
+/// ```
+#[derive(Clone, Debug)]
+struct RollingHash {
+    s: Vec<u64>,
+    hash: Vec<u64>,
+    pow: Vec<u64>,
+}
+
+impl RollingHash {
+    fn new<T: Copy + Into<u64>>(s: &[T]) -> Self {
+        let base = rolling_hash_base();
+        let n = s.len();
+        let s = s.iter().map(|&c| c.into()).collect::<Vec<u64>>();
+        let mut hash = vec![0; n + 1];
+        let mut pow = vec![1; n + 1];
+        for i in 0..n {
+            hash[i + 1] = rolling_hash_add(rolling_hash_mul(hash[i], base), s[i]);
+            pow[i + 1] = rolling_hash_mul(pow[i], base);
+        }
+        RollingHash { s, hash, pow }
+    }
+
+    fn len(&self) -> usize {
+        self.s.len()
+    }
+
+    /// 部分文字列 `s[l..r]` のハッシュ値を返す。
+    fn get(&self, l: usize, r: usize) -> u64 {
+        assert!(l <= r && r <= self.len());
+        let m = ROLLING_HASH_MOD as u64;
+        rolling_hash_add(self.hash[r], m - rolling_hash_mul(self.hash[l], self.pow[r - l]))
+    }
+
+    /// ハッシュ値 `h1` の文字列の後ろに、長さ `len2` でハッシュ値 `h2` の文字列を連結した文字列のハッシュ値を返す。
+    fn concat(&self, h1: u64, h2: u64, len2: usize) -> u64 {
+        let p = if len2 <= self.len() {
+            self.pow[len2]
+        } else {
+            let mut p = 1;
+            let mut b = rolling_hash_base();
+            let mut k = len2;
+            while k > 0 {
+                if k & 1 == 1 {
+                    p = rolling_hash_mul(p, b);
+                }
+                b = rolling_hash_mul(b, b);
+                k >>= 1;
+            }
+            p
+        };
+        rolling_hash_add(rolling_hash_mul(h1, p), h2)
+    }
+
+    /// `self` の `a` 文字目以降と `other` の `b` 文字目以降の最長共通接頭辞の長さを返す。
+    fn lcp(&self, a: usize, other: &RollingHash, b: usize) -> usize {
+        let max_len = (self.len() - a).min(other.len() - b);
+        meguru_bisect(0, max_len + 1, |k| self.get(a, a + k) == other.get(b, b + k))
+    }
+
+    /// `self` の部分文字列 `[l1, r1)` と `other` の部分文字列 `[l2, r2)` を辞書順で比較する。
+    fn compare(
+        &self,
+        l1: usize,
+        r1: usize,
+        other: &RollingHash,
+        l2: usize,
+        r2: usize,
+    ) -> std::cmp::Ordering {
+        let k = self.lcp(l1, other, l2).min(r1 - l1).min(r2 - l2);
+        if l1 + k == r1 || l2 + k == r2 {
+            (r1 - l1).cmp(&(r2 - l2))
+        } else {
+            self.s[l1 + k].cmp(&other.s[l2 + k])
+        }
+    }
+}
+
+/***********************************************************
+* Number Theory
+************************************************************/
+/// 指定された整数の素因数分解を行う。
+///
+/// 与えられた正の整数 `n` を素因数分解し、
+/// 各素因数とその指数を `[素因数, 指数]` の形式の配列として `Vec` に格納して返す。
+///
+/// # 例
+///
+/// ```
+/// let factors = factorization(12);
+/// // factors は [[2, 2], [3, 1]] となる
+/// ```
+fn factorization(n: i64) -> Vec<[i64; 2]> {
+    let mut arr = Vec::new();
+    let mut temp = n;
+    let limit = (n as f64).sqrt().ceil() as i64;
+
+    for i in 2..=limit {
+        if temp % i == 0 {
+            let mut cnt = 0;
+            while temp % i == 0 {
+                cnt += 1;
+                temp /= i;
+            }
+            arr.push([i, cnt]);
+        }
+    }
+
+    if temp != 1 {
+        arr.push([temp, 1]);
+    }
+
+    if arr.is_empty() {
+        arr.push([n, 1]);
+    }
+
+    arr
+}
+
+/// 指定された整数の全ての正の約数を取得し、昇順に並べたベクターを返す。
+///
+/// # 引数
+///
+/// * `n` - 約数を求めたい正の整数。
+///
+/// # 戻り値
+///
+/// `n` の全ての正の約数を昇順に並べた `Vec<i64>` を返す。
+///
+/// # 例
+///
+/// ```
+/// let divs = divisors(36);
+/// // 結果は [1, 2, 3, 4, 6, 9, 12, 18, 36] となる
+/// ```
+fn divisors(n: i64) -> Vec<i64> {
+    let mut l1 = Vec::new();
+    let mut l2 = Vec::new();
+    let mut i = 1;
+    while i * i <= n {
+        if n % i == 0 {
+            l1.push(i);
+            if i != n / i {
+                l2.push(n / i);
+            }
+        }
+        i += 1;
+    }
+    l2.reverse();
+    l1.extend(l2);
+    l1
+}
+
+
+/***********************************************************
+* Encoding
+************************************************************/
+/// 座標圧縮
+///
+/// # 引数
+/// - `a`: 座標圧縮を行う整数のベクター
+///
+/// # 戻り値
+/// 元のベクターの各要素を一意なランク（1始まり）に置換した新たなベクターを返す。
+///
+/// # 例
+/// ```
+/// let v = vec![40, 10, 20, 20, 30];
+/// let compressed = compress(&v);
+/// assert_eq!(compressed, vec![4, 1, 2, 2, 3]);
+/// ```
+fn compress(a: &[i64]) -> Vec<i64> {
+    let mut b = a.to_vec();
+    b.sort();
+    b.dedup();
+
+    let mut rank: HashMap<i64, i64> = HashMap::new();
+    for (i, &x) in b.iter().enumerate() {
+        rank.insert(x, i as i64 + 1);
+    }
+
+    a.iter().map(|&x| rank[&x]).collect()
+}
+
+/// ランレングス圧縮
+///
+/// # 使用例
+///     run_length_encode("aaabbccccaa".chars())
+///
+/// # 引数
+///
+/// - `data`: 圧縮対象のデータスライス。要素が `Eq` と `Clone` を実装している必要がある。
+///
+/// # 戻り値
+///
+/// `(T, usize)` のベクタ。`T` は各区間の要素、`usize` は連続して現れた回数。
+///
+/// # 例: vec![('a', 3), ('b', 2), ('c', 4), ('a', 2)]
+///
+pub fn run_length_encode<I, T>(data: I) -> Vec<(T, usize)>
+where
+    I: IntoIterator<Item = T>,
+    T: Eq + Clone,
+{
+    let mut iter = data.into_iter();
+    let first = match iter.next() {
+        Some(x) => x,
+        None => return Vec::new(),
+    };
+
+    let mut current = first;
+    let mut count = 1;
+    let mut result = Vec::new();
+
+    for item in iter {
+        if item == current {
+            count += 1;
+        } else {
+            result.push((current, count));
+            current = item;
+            count = 1;
+        }
+    }
+    result.push((current, count));
+    result
+}
+
+/// ランレングス圧縮のデコード
+///
+/// # 引数
+///
+/// - `encoded`: ランレングス圧縮された `(T, usize)` のスライス
+///
+/// # 戻り値
+///
+/// 元のデータ列を格納した `Vec<T>`
+///
+/// # 例
+///
+/// ```
+/// let encoded = vec![('a', 3), ('b', 2), ('c', 4), ('a', 2)];
+/// let decoded = run_length_decode(&encoded);
+/// // => ['a', 'a', 'a', 'b', 'b', 'c', 'c', 'c', 'c', 'a', 'a']
+/// println!(decoded.iter().collect::<String>())
+/// // => "aaabbccccaa"
+/// ```
+pub fn run_length_decode<T>(encoded: &[(T, usize)]) -> Vec<T>
+where
+    T: Clone,
+{
+    encoded
+        .iter()
+        .flat_map(|(value, count)| std::iter::repeat(value.clone()).take(*count))
+        .collect()
+}
+
+/***********************************************************
+* Cumulative Sum
+************************************************************/
+/// 1次元累積和
+///
+/// 前計算 O(N) で、半開区間 `[l, r)` の和を O(1) で求める。
+///
+/// # 例
+///
+/// ```
+/// let cs = CumSum::new(&[3, 1, 4, 1, 5]);
+/// assert_eq!(cs.sum(1, 4), 6);
+/// assert_eq!(cs.sum(2, 2), 0);
+/// ```
+#[derive(Clone, Debug)]
+struct CumSum<T> {
+    s: Vec<T>,
+}
+
+impl<T> CumSum<T>
+where
+    T: Copy + Default + std::ops::Add<Output = T> + std::ops::Sub<Output = T>,
+{
+    fn new(a: &[T]) -> Self {
+        CumSum {
+            s: prefix_fold(a, T::default(), |&x, &y| x + y),
+        }
+    }
+
+    /// `a[l..r]` の和を返す。
+    fn sum(&self, l: usize, r: usize) -> T {
+        assert!(l <= r);
+        self.s[r] - self.s[l]
+    }
+
+    /// `a[..i]` の和を返す。
+    fn prefix(&self, i: usize) -> T {
+        self.s[i]
+    }
+}
+
+/// 2次元累積和
+///
+/// 前計算 O(HW) で、長方形領域 `[i1, i2) × [j1, j2)` の和を O(1) で求める。
+///
+/// # 例
+///
+/// ```
+/// let cs = CumSum2d::new(&[vec![1, 2, 3], vec![4, 5, 6]]);
+/// assert_eq!(cs.sum(0, 1, 2, 3), 16); // 2 + 3 + 5 + 6
+/// ```
+#[derive(Clone, Debug)]
+struct CumSum2d<T> {
+    s: Vec<Vec<T>>,
+}
+
+impl<T> CumSum2d<T>
+where
+    T: Copy + Default + std::ops::Add<Output = T> + std::ops::Sub<Output = T>,
+{
+    fn new(a: &[Vec<T>]) -> Self {
+        let h = a.len();
+        let w = a.first().map_or(0, |row| row.len());
+        let mut s = vec![vec![T::default(); w + 1]; h + 1];
+        for i in 0..h {
+            for j in 0..w {
+                s[i + 1][j + 1] = s[i][j + 1] + s[i + 1][j] - s[i][j] + a[i][j];
+            }
+        }
+        CumSum2d { s }
+    }
+
+    /// `i1 <= i < i2`、`j1 <= j < j2` を満たす `a[i][j]` の和を返す。
+    fn sum(&self, i1: usize, j1: usize, i2: usize, j2: usize) -> T {
+        assert!(i1 <= i2 && j1 <= j2);
+        self.s[i2][j2] - self.s[i1][j2] - self.s[i2][j1] + self.s[i1][j1]
+    }
+}
+
+/// 1次元いもす法（差分配列）
+///
+/// 区間加算を O(1) で記録し、最後に `build` で O(N) かけて各要素の値を求める。
+///
+/// # 例
+///
+/// ```
+/// let mut imos = Imos::new(5);
+/// imos.add(1, 3, 2);
+/// imos.add(2, 5, 1);
+/// assert_eq!(imos.build(), vec![0, 2, 3, 1, 1]);
+/// ```
+#[derive(Clone, Debug)]
+struct Imos<T> {
+    d: Vec<T>,
+}
+
+impl<T> Imos<T>
+where
+    T: Copy + Default + std::ops::Add<Output = T> + std::ops::Sub<Output = T>,
+{
+    fn new(n: usize) -> Self {
+        Imos {
+            d: vec![T::default(); n + 1],
+        }
+    }
+
+    /// 半開区間 `[l, r)` に `x` を加える。
+    fn add(&mut self, l: usize, r: usize, x: T) {
+        assert!(l <= r && r < self.d.len());
+        self.d[l] = self.d[l] + x;
+        self.d[r] = self.d[r] - x;
+    }
+
+    /// 各要素の値を求める。
+    fn build(&self) -> Vec<T> {
+        let n = self.d.len() - 1;
+        let mut res = Vec::with_capacity(n);
+        let mut acc = T::default();
+        for &x in &self.d[..n] {
+            acc = acc + x;
+            res.push(acc);
+        }
+        res
+    }
+}
+
+/// 2次元いもす法（差分配列）
+///
+/// 長方形領域への加算を O(1) で記録し、最後に `build` で O(HW) かけて各要素の値を求める。
+///
+/// # 例
+///
+/// ```
+/// let mut imos = Imos2d::new(2, 3);
+/// imos.add(0, 1, 2, 3, 5);
+/// assert_eq!(imos.build(), vec![vec![0, 5, 5], vec![0, 5, 5]]);
+/// ```
+#[derive(Clone, Debug)]
+struct Imos2d<T> {
+    d: Vec<Vec<T>>,
+}
+
+impl<T> Imos2d<T>
+where
+    T: Copy + Default + std::ops::Add<Output = T> + std::ops::Sub<Output = T>,
+{
+    fn new(h: usize, w: usize) -> Self {
+        Imos2d {
+            d: vec![vec![T::default(); w + 1]; h + 1],
+        }
+    }
+
+    /// `i1 <= i < i2`、`j1 <= j < j2` の領域に `x` を加える。
+    fn add(&mut self, i1: usize, j1: usize, i2: usize, j2: usize, x: T) {
+        assert!(i1 <= i2 && j1 <= j2);
+        self.d[i1][j1] = self.d[i1][j1] + x;
+        self.d[i1][j2] = self.d[i1][j2] - x;
+        self.d[i2][j1] = self.d[i2][j1] - x;
+        self.d[i2][j2] = self.d[i2][j2] + x;
+    }
+
+    /// 各要素の値を求める。
+    fn build(&self) -> Vec<Vec<T>> {
+        let h = self.d.len() - 1;
+        let w = self.d[0].len() - 1;
+        let mut res = vec![vec![T::default(); w]; h];
+        for i in 0..h {
+            for j in 0..w {
+                let mut x = self.d[i][j];
+                if i > 0 {
+                    x = x + res[i - 1][j];
+                }
+                if j > 0 {
+                    x = x + res[i][j - 1];
+                }
+                if i > 0 && j > 0 {
+                    x = x - res[i - 1][j - 1];
+                }
+                res[i][j] = x;
+            }
+        }
+        res
+    }
+}
+
+/// 累積 max。`res[i]` は `a[..=i]` の最大値。
+fn prefix_max<T: Ord + Copy>(a: &[T]) -> Vec<T> {
+    let mut res = a.to_vec();
+    for i in 1..res.len() {
+        res[i] = max!(res[i - 1], res[i]);
+    }
+    res
+}
+
+/// 累積 min。`res[i]` は `a[..=i]` の最小値。
+fn prefix_min<T: Ord + Copy>(a: &[T]) -> Vec<T> {
+    let mut res = a.to_vec();
+    for i in 1..res.len() {
+        res[i] = min!(res[i - 1], res[i]);
+    }
+    res
+}
+
+/// モノイドによる累積演算
+///
+/// 単位元 `id` と結合的な演算 `op` について、`res[i] = op(a[0], ..., a[i - 1])`（`res[0] = id`）となる
+/// 長さ `N + 1` のベクターを返す。
+///
+/// # 例
+///
+/// ```
+/// let a = vec![3, 1, 4];
+/// assert_eq!(prefix_fold(&a, 1, |x, y| x * y), vec![1, 3, 3, 12]);
+/// assert_eq!(prefix_fold(&a, 0, |&x, &y| x ^ y), vec![0, 3, 2, 6]);
+/// ```
+fn prefix_fold<T: Clone>(a: &[T], id: T, op: impl Fn(&T, &T) -> T) -> Vec<T> {
+    let mut res = Vec::with_capacity(a.len() + 1);
+    res.push(id);
+    for x in a {
+        let next = op(res.last().unwrap(), x);
+        res.push(next);
+    }
+    res
+}
+
 /***********************************************************
-* Bitwise Calculations
+* Binary Search
 ************************************************************/
-/// 整数 `num` の `shift` ビット目が1であるかどうかを確認する。
-/// ビット位置は0から始まり、0が最下位ビットを表す。
+/// めぐる式二分探索で扱える整数型。
+/// `ok` と `ng` の中点をオーバーフロー・アンダーフローなしに計算する。
+pub trait BisectInt: Copy + Ord {
+    /// `a` と `b` の中点（切り捨て）を返す。
+    fn mid(a: Self, b: Self) -> Self;
+    /// `a` と `b` の間に整数が存在する（差が2以上である）かどうかを返す。
+    fn has_gap(a: Self, b: Self) -> bool;
+}
+
+macro_rules! impl_bisect_int {
+    ($($t:ty),*) => {$(
+        impl BisectInt for $t {
+            fn mid(a: Self, b: Self) -> Self {
+                (a & b) + ((a ^ b) >> 1)
+            }
+            fn has_gap(a: Self, b: Self) -> bool {
+                a.abs_diff(b) > 1
+            }
+        }
+    )*};
+}
+impl_bisect_int!(i32, i64, isize, u32, u64, usize);
+
+/// めぐる式二分探索
+///
+/// `f(ok)` が `true`、`f(ng)` が `false` となる境界を探す。
+/// `f` は `ok` 側から `ng` 側に向かって `true` → `false` と単調に変化する必要がある。
+/// `ok < ng`、`ok > ng` のどちらの向きでもよい。
+/// `f(ok)`、`f(ng)` 自体は評価しないので、範囲外の番兵を渡してよい。
 ///
 /// # 引数
 ///
-/// * `num` - 判定対象の整数。
-/// * `shift` - チェックするビットの位置。0が最下位ビット。
+/// * `ok` - 条件を満たすことが分かっている値（番兵）。
+/// * `ng` - 条件を満たさないことが分かっている値（番兵）。
+/// * `f` - 単調な判定関数。
 ///
 /// # 戻り値
 ///
-/// 指定されたビット位置にビットが立っている場合は `true`、そうでない場合は `false` 。
-fn has_bit(num: i64, shift: u32) -> bool {
-    ((num >> shift) & 1) == 1
-}
-
-/***********************************************************
-* String
-************************************************************/
-/// 与えられたイテレータの各要素を文字列に変換し、
-/// 指定された区切り文字で連結した1つの文字列を返す。
+/// `f(x)` が `true` となる `x` のうち、最も `ng` に近い値。
 ///
 /// # 例
+///
 /// ```
-/// let v = vec![1, 2, 3];
-/// let joined = join_with(v, " ");
-/// assert_eq!(joined, "1 2 3");
+/// // x * x <= 10 を満たす最大の x
+/// let x = meguru_bisect(0_i64, 10, |x| x * x <= 10);
+/// assert_eq!(x, 3);
+/// // usize で 0 を下回らずに「a[i] >= 5 となる最小の i（なければ a.len()）」を探す。
+/// // 番兵 ok = a.len() + 1 は範囲外の a[a.len()] を「条件を満たす」とみなすもの。
+/// // ok = a.len() とすると a[a.len() - 1] を調べずに満たすとみなしてしまうので注意。
+/// let a = vec![1, 3, 5, 7];
+/// let i = meguru_bisect(a.len() + 1, 0_usize, |i| a[i - 1] >= 5) - 1;
+/// assert_eq!(i, 2);
+/// let b = vec![1, 3, 4, 4];
+/// assert_eq!(meguru_bisect(b.len() + 1, 0_usize, |i| b[i - 1] >= 5) - 1, 4);
+/// // このような探索は lower_bound(&a, &5) でも書ける
 /// ```
-fn join_with<I>(iter: I, sep: &str) -> String
-where
-    I: IntoIterator,
-    I::Item: std::fmt::Display,
-{
-    iter.into_iter()
-        .map(|item| item.to_string())
-        .collect::<Vec<String>>()
-        .join(sep)
+fn meguru_bisect<T: BisectInt>(mut ok: T, mut ng: T, mut f: impl FnMut(T) -> bool) -> T {
+    while T::has_gap(ok, ng) {
+        let mid = T::mid(ok, ng);
+        if f(mid) {
+            ok = mid;
+        } else {
+            ng = mid;
+        }
+    }
+    ok
 }
 
-/***********************************************************
-* Number Theory
-************************************************************/
-/// 指定された整数の素因数分解を行う。
+/// 実数上のめぐる式二分探索
 ///
-/// 与えられた正の整数 `n` を素因数分解し、
-/// 各素因数とその指数を `[素因数, 指数]` の形式の配列として `Vec` に格納して返す。
+/// `meguru_bisect` の `f64` 版。区間の幅ではなく反復回数で打ち切るため、
+/// 誤差で無限ループすることがない。`iter` は通常 100 程度で十分。
 ///
 /// # 例
 ///
 /// ```
-/// let factors = factorization(12);
-/// // factors は [[2, 2], [3, 1]] となる
+/// // x * x <= 2 を満たす最大の x
+/// let x = meguru_bisect_f64(0.0, 2.0, 100, |x| x * x <= 2.0);
+/// assert!((x - 2.0_f64.sqrt()).abs() < 1e-9);
 /// ```
-fn factorization(n: i64) -> Vec<[i64; 2]> {
-    let mut arr = Vec::new();
-    let mut temp = n;
-    let limit = (n as f64).sqrt().ceil() as i64;
-
-    for i in 2..=limit {
-        if temp % i == 0 {
-            let mut cnt = 0;
-            while temp % i == 0 {
-                cnt += 1;
-                temp /= i;
-            }
-            arr.push([i, cnt]);
+fn meguru_bisect_f64(mut ok: f64, mut ng: f64, iter: usize, mut f: impl FnMut(f64) -> bool) -> f64 {
+    for _ in 0..iter {
+        let mid = (ok + ng) / 2.0;
+        if f(mid) {
+            ok = mid;
+        } else {
+            ng = mid;
         }
     }
+    ok
+}
 
-    if temp != 1 {
-        arr.push([temp, 1]);
-    }
+/// ソート済みスライス `v` に対して、`v[i] >= x` となる最小の `i` を返す。
+/// 該当する要素がない場合は `v.len()` を返す。
+///
+/// # 例
+///
+/// ```
+/// let v = vec![1, 2, 2, 4];
+/// assert_eq!(lower_bound(&v, &2), 1);
+/// assert_eq!(lower_bound(&v, &5), 4);
+/// ```
+fn lower_bound<T: Ord>(v: &[T], x: &T) -> usize {
+    v.partition_point(|a| a < x)
+}
 
-    if arr.is_empty() {
-        arr.push([n, 1]);
-    }
+/// ソート済みスライス `v` に対して、`v[i] > x` となる最小の `i` を返す。
+/// 該当する要素がない場合は `v.len()` を返す。
+///
+/// # 例
+///
+/// ```
+/// let v = vec![1, 2, 2, 4];
+/// assert_eq!(upper_bound(&v, &2), 3);
+/// ```
+fn upper_bound<T: Ord>(v: &[T], x: &T) -> usize {
+    v.partition_point(|a| a <= x)
+}
 
-    arr
+/// ソート済みスライス `v` のうち、`x` と等しい要素が並ぶ半開区間 `[l, r)` を返す。
+///
+/// # 例
+///
+/// ```
+/// let v = vec![1, 2, 2, 4];
+/// assert_eq!(equal_range(&v, &2), (1, 3));
+/// assert_eq!(equal_range(&v, &3), (3, 3));
+/// ```
+fn equal_range<T: Ord>(v: &[T], x: &T) -> (usize, usize) {
+    (lower_bound(v, x), upper_bound(v, x))
 }
 
-/// 指定された整数の全ての正の約数を取得し、昇順に並べたベクターを返す。
+/// ソート済みスライス `v` のうち、`lo <= v[i] < hi` を満たす要素の個数を返す。
+/// `lo >= hi` の場合は 0 を返す。
+///
+/// # 例
+///
+/// ```
+/// let v = vec![1, 2, 2, 4, 7];
+/// assert_eq!(count_in_range(&v, &2, &5), 3);
+/// ```
+fn count_in_range<T: Ord>(v: &[T], lo: &T, hi: &T) -> usize {
+    lower_bound(v, hi).saturating_sub(lower_bound(v, lo))
+}
+
+/// 並列二分探索
+///
+/// 操作列 `0..steps` を順に適用していくとき、各クエリ `i` について
+/// 「最初の `k` 個の操作を適用した状態で `check(state, i)` が `true` になる最小の `k`」を
+/// すべてのクエリについてまとめて求める。`check` は `k` について単調である必要がある。
+/// 計算量は O((steps + q) log steps) 回の `apply` / `check` 呼び出し。
 ///
 /// # 引数
 ///
-/// * `n` - 約数を求めたい正の整数。
+/// * `q` - クエリの個数。
+/// * `steps` - 操作の個数。
+/// * `reset` - 操作を1つも適用していない初期状態を返す。
+/// * `apply` - 状態に `t` 番目（0始まり）の操作を適用する。
+/// * `check` - 状態がクエリ `i` の条件を満たすかどうかを返す。
 ///
 /// # 戻り値
 ///
-/// `n` の全ての正の約数を昇順に並べた `Vec<i64>` を返す。
+/// 各クエリについて `0..=steps` の値。全操作を適用しても条件を満たさない場合は `steps + 1`。
 ///
 /// # 例
 ///
 /// ```
-/// let divs = divisors(36);
-/// // 結果は [1, 2, 3, 4, 6, 9, 12, 18, 36] となる
+/// // 辺を順に追加していき、u[i] と v[i] が連結になる最初の時刻
+/// let ans = parallel_binary_search(
+///     q,
+///     m,
+///     || Dsu::new(n),
+///     |dsu, t| {
+///         dsu.merge(edges[t].0, edges[t].1);
+///     },
+///     |dsu, i| dsu.same(u[i], v[i]),
+/// );
+/// // ans[i] == k なら k 本目（1始まり）の辺を追加した時点で初めて連結になる
 /// ```
-fn divisors(n: i64) -> Vec<i64> {
-    let mut l1 = Vec::new();
-    let mut l2 = Vec::new();
-    let mut i = 1;
-    while i * i <= n {
-        if n % i == 0 {
-            l1.push(i);
-            if i != n / i {
-                l2.push(n / i);
+fn parallel_binary_search<S>(
+    q: usize,
+    steps: usize,
+    mut reset: impl FnMut() -> S,
+    mut apply: impl FnMut(&mut S, usize),
+    mut check: impl FnMut(&mut S, usize) -> bool,
+) -> Vec<usize> {
+    let mut lo = vec![0; q];
+    let mut hi = vec![steps + 1; q];
+    loop {
+        let mut buckets = vec![Vec::new(); steps + 1];
+        let mut updated = false;
+        for i in 0..q {
+            if lo[i] < hi[i] {
+                buckets[(lo[i] + hi[i]) / 2].push(i);
+                updated = true;
+            }
+        }
+        if !updated {
+            break;
+        }
+        let mut state = reset();
+        for (k, bucket) in buckets.iter().enumerate() {
+            if k > 0 {
+                apply(&mut state, k - 1);
+            }
+            for &i in bucket {
+                if check(&mut state, i) {
+                    hi[i] = k;
+                } else {
+                    lo[i] = k + 1;
+                }
             }
         }
-        i += 1;
     }
-    l2.reverse();
-    l1.extend(l2);
-    l1
+    lo
 }
 
-
 /***********************************************************
-* Encoding
+* Ternary Search
 ************************************************************/
-/// 座標圧縮
+/// 整数上の三分探索
 ///
-/// # 引数
-/// - `a`: 座標圧縮を行う整数のベクター
-///
-/// # 戻り値
-/// 元のベクターの各要素を一意なランク（1始まり）に置換した新たなベクターを返す。
+/// 閉区間 `[lo, hi]` で下に凸な関数 `f` の最小値とそれを与える `x` を返す。
+/// `f(m1) == f(m2)` のときは最小値が `[m1, m2]` に含まれることを利用して右端だけを縮めるため、
+/// 最小値付近が平らな（同じ値が続く）関数でも正しく動作する。
+/// 最小値を与える `x` が複数ある場合は最も小さい `x` を返す。
+/// 最大値を求める場合は `f` の符号を反転させる。
 ///
 /// # 例
+///
 /// ```
-/// let v = vec![40, 10, 20, 20, 30];
-/// let compressed = compress(&v);
-/// assert_eq!(compressed, vec![4, 1, 2, 2, 3]);
+/// let (x, v) = ternary_search_min(-10, 10, |x| (x - 3).abs().max(1));
+/// assert_eq!((x, v), (2, 1));
 /// ```
-fn compress(a: &[i64]) -> Vec<i64> {
-    let mut b = a.to_vec();
-    b.sort();
-    b.dedup();
-
-    let mut rank: HashMap<i64, i64> = HashMap::new();
-    for (i, &x) in b.iter().enumerate() {
-        rank.insert(x, i as i64 + 1);
+fn ternary_search_min<T: PartialOrd + Copy>(
+    mut lo: i64,
+    mut hi: i64,
+    mut f: impl FnMut(i64) -> T,
+) -> (i64, T) {
+    assert!(lo <= hi);
+    while hi - lo > 2 {
+        let m1 = lo + (hi - lo) / 3;
+        let m2 = hi - (hi - lo) / 3;
+        let (f1, f2) = (f(m1), f(m2));
+        if f1 < f2 {
+            hi = m2 - 1;
+        } else if f1 > f2 {
+            lo = m1 + 1;
+        } else {
+            hi = m2;
+        }
     }
-
-    a.iter().map(|&x| rank[&x]).collect()
+    let mut best = (lo, f(lo));
+    for x in lo + 1..=hi {
+        let v = f(x);
+        if v < best.1 {
+            best = (x, v);
+        }
+    }
+    best
 }
 
-/// ランレングス圧縮
+/// 整数上の凸関数の最小化
 ///
-/// # 使用例
-///     run_length_encode("aaabbccccaa".chars())
-///
-/// # 引数
+/// 閉区間 `[lo, hi]` で下に凸な関数 `f` について、`f(x) <= f(x + 1)` となる最小の `x` を
+/// 二分探索で求め、最小値とそれを与える `x` を返す。
+/// 三分探索より関数の評価回数が少ない（約 2 log(hi - lo) 回）。
 ///
-/// - `data`: 圧縮対象のデータスライス。要素が `Eq` と `Clone` を実装している必要がある。
+/// # 例
 ///
-/// # 戻り値
+/// ```
+/// let (x, v) = convex_argmin(0, 100, |x| (x - 40) * (x - 40) + 7);
+/// assert_eq!((x, v), (40, 7));
+/// ```
+fn convex_argmin<T: PartialOrd + Copy>(lo: i64, hi: i64, mut f: impl FnMut(i64) -> T) -> (i64, T) {
+    assert!(lo <= hi);
+    let x = meguru_bisect(hi, lo - 1, |x| f(x) <= f(x + 1));
+    (x, f(x))
+}
+
+/// 黄金分割探索
 ///
-/// `(T, usize)` のベクタ。`T` は各区間の要素、`usize` は連続して現れた回数。
+/// 閉区間 `[lo, hi]` で単峰な（最小値まで単調減少し、その後単調増加する）関数 `f` の
+/// 最小値とそれを与える `x` を返す。
+/// 1回の反復で区間が約 0.618 倍になり、関数の評価は1回で済む。`iter` は通常 100 程度で十分。
+/// 最大値を求める場合は `f` の符号を反転させる。
 ///
-/// # 例: vec![('a', 3), ('b', 2), ('c', 4), ('a', 2)]
+/// # 例
 ///
-pub fn run_length_encode<I, T>(data: I) -> Vec<(T, usize)>
-where
-    I: IntoIterator<Item = T>,
-    T: Eq + Clone,
-{
-    let mut iter = data.into_iter();
-    let first = match iter.next() {
-        Some(x) => x,
-        None => return Vec::new(),
-    };
-
-    let mut current = first;
-    let mut count = 1;
-    let mut result = Vec::new();
-
-    for item in iter {
-        if item == current {
-            count += 1;
+/// ```
+/// let (x, v) = golden_section_search(-10.0, 10.0, 100, |x| (x - 1.5) * (x - 1.5));
+/// assert!((x - 1.5).abs() < 1e-9);
+/// ```
+fn golden_section_search(
+    mut lo: f64,
+    mut hi: f64,
+    iter: usize,
+    mut f: impl FnMut(f64) -> f64,
+) -> (f64, f64) {
+    let r = (5.0_f64.sqrt() - 1.0) / 2.0;
+    let mut x1 = hi - r * (hi - lo);
+    let mut x2 = lo + r * (hi - lo);
+    let mut f1 = f(x1);
+    let mut f2 = f(x2);
+    for _ in 0..iter {
+        if f1 < f2 {
+            hi = x2;
+            x2 = x1;
+            f2 = f1;
+            x1 = hi - r * (hi - lo);
+            f1 = f(x1);
         } else {
-            result.push((current, count));
-            current = item;
-            count = 1;
+            lo = x1;
+            x1 = x2;
+            f1 = f2;
+            x2 = lo + r * (hi - lo);
+            f2 = f(x2);
         }
     }
-    result.push((current, count));
-    result
+    let x = (lo + hi) / 2.0;
+    (x, f(x))
 }
 
-/// ランレングス圧縮のデコード
+/***********************************************************
+* Sequence DP
+************************************************************/
+/// 編集距離で `a` を `b` に変換する1回分の操作
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum EditOp {
+    /// `a` の文字をそのまま使う
+    Keep,
+    /// `a` の文字を `b` の文字に置き換える
+    Replace,
+    /// `b` の文字を挿入する
+    Insert,
+    /// `a` の文字を削除する
+    Delete,
+}
+
+/// 編集距離（レーベンシュタイン距離）
 ///
-/// # 引数
+/// `a` を `b` に変換するのに必要な挿入・削除・置換の最小回数と、
+/// 先頭から順に適用する操作列の一例を返す。計算量は O(NM)。
 ///
-/// - `encoded`: ランレングス圧縮された `(T, usize)` のスライス
+/// # 例
 ///
-/// # 戻り値
+/// ```
+/// let a = "kitten".chars().collect::<Vec<_>>();
+/// let b = "sitting".chars().collect::<Vec<_>>();
+/// let (d, ops) = edit_distance(&a, &b);
+/// assert_eq!(d, 3);
+/// ```
+fn edit_distance<T: Eq>(a: &[T], b: &[T]) -> (usize, Vec<EditOp>) {
+    let (n, m) = (a.len(), b.len());
+    let mut dp = vec![vec![0; m + 1]; n + 1];
+    for (i, row) in dp.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for (j, x) in dp[0].iter_mut().enumerate() {
+        *x = j;
+    }
+    for i in 1..=n {
+        for j in 1..=m {
+            let cost = if a[i - 1] == b[j - 1] { 0 } else { 1 };
+            dp[i][j] = min!(dp[i - 1][j - 1] + cost, dp[i - 1][j] + 1, dp[i][j - 1] + 1);
+        }
+    }
+    let mut ops = Vec::with_capacity(n.max(m));
+    let (mut i, mut j) = (n, m);
+    while i > 0 || j > 0 {
+        if i > 0 && j > 0 && a[i - 1] == b[j - 1] && dp[i][j] == dp[i - 1][j - 1] {
+            ops.push(EditOp::Keep);
+            i -= 1;
+            j -= 1;
+        } else if i > 0 && j > 0 && dp[i][j] == dp[i - 1][j - 1] + 1 {
+            ops.push(EditOp::Replace);
+            i -= 1;
+            j -= 1;
+        } else if i > 0 && dp[i][j] == dp[i - 1][j] + 1 {
+            ops.push(EditOp::Delete);
+            i -= 1;
+        } else {
+            ops.push(EditOp::Insert);
+            j -= 1;
+        }
+    }
+    ops.reverse();
+    (dp[n][m], ops)
+}
+
+/// 最長共通部分列
 ///
-/// 元のデータ列を格納した `Vec<T>`
+/// `a` と `b` の最長共通部分列の一例を返す（長さが最適値）。計算量は O(NM)。
 ///
 /// # 例
 ///
 /// ```
-/// let encoded = vec![('a', 3), ('b', 2), ('c', 4), ('a', 2)];
-/// let decoded = run_length_decode(&encoded);
-/// // => ['a', 'a', 'a', 'b', 'b', 'c', 'c', 'c', 'c', 'a', 'a']
-/// println!(decoded.iter().collect::<String>())
-/// // => "aaabbccccaa"
+/// let a = "abcbdab".chars().collect::<Vec<_>>();
+/// let b = "bdcaba".chars().collect::<Vec<_>>();
+/// assert_eq!(lcs(&a, &b).len(), 4);
 /// ```
-pub fn run_length_decode<T>(encoded: &[(T, usize)]) -> Vec<T>
-where
-    T: Clone,
-{
-    encoded
-        .iter()
-        .flat_map(|(value, count)| std::iter::repeat(value.clone()).take(*count))
-        .collect()
+fn lcs<T: Eq + Clone>(a: &[T], b: &[T]) -> Vec<T> {
+    let (n, m) = (a.len(), b.len());
+    let mut dp = vec![vec![0; m + 1]; n + 1];
+    for i in 0..n {
+        for j in 0..m {
+            dp[i + 1][j + 1] = if a[i] == b[j] {
+                dp[i][j] + 1
+            } else {
+                dp[i][j + 1].max(dp[i + 1][j])
+            };
+        }
+    }
+    let mut res = Vec::with_capacity(dp[n][m]);
+    let (mut i, mut j) = (n, m);
+    while i > 0 && j > 0 {
+        if a[i - 1] == b[j - 1] {
+            res.push(a[i - 1].clone());
+            i -= 1;
+            j -= 1;
+        } else if dp[i][j] == dp[i - 1][j] {
+            i -= 1;
+        } else {
+            j -= 1;
+        }
+    }
+    res.reverse();
+    res
 }
 
-/***********************************************************
-* Binary Search
-************************************************************/
-/// スライス `v` に対して、要素 `x` を左側（最初に `x` 以上となる位置）に挿入するためのインデックスを返す。
-/// つまり、`v[..i]` は全て `x` より小さく、`v[i..]` は `x` 以上となる最小の `i` を返す。
-pub fn bisect_left<T: Ord>(v: &[T], x: &T) -> i32 {
-    let mut left = 0i32;
-    let mut right = v.len() as i32 - 1;
-    while left <= right {
-        let mid = (left + right) / 2;
-        if v[mid as usize] < *x {
-            left = mid + 1;
-        } else {
-            right = mid - 1;
+/// 最長共通部分列（Hunt-Szymanski のアルゴリズム）
+///
+/// 一致する添字の組 `(i, j)`（`a[i] == b[j]`）の個数を R として O((R + N) log N) で動作する。
+/// 文字種が多く一致が少ない場合に `lcs` より高速。
+///
+/// # 例
+///
+/// ```
+/// let a = vec![1, 5, 2, 8, 3];
+/// let b = vec![5, 9, 2, 3, 1];
+/// assert_eq!(lcs_hunt_szymanski(&a, &b), vec![5, 2, 3]);
+/// ```
+fn lcs_hunt_szymanski<T: Ord + Clone>(a: &[T], b: &[T]) -> Vec<T> {
+    let mut positions: BTreeMap<&T, Vec<usize>> = BTreeMap::new();
+    for (j, x) in b.iter().enumerate().rev() {
+        positions.entry(x).or_default().push(j);
+    }
+    // tails[k]: 長さ k + 1 の共通部分列の末尾 (b での位置, ノード番号) のうち b での位置が最小のもの
+    let mut tails: Vec<(usize, usize)> = Vec::new();
+    // nodes[id]: (a での位置, 直前のノード番号)
+    let mut nodes: Vec<(usize, Option<usize>)> = Vec::new();
+    for (i, x) in a.iter().enumerate() {
+        if let Some(js) = positions.get(x) {
+            for &j in js {
+                let k = tails.partition_point(|&(t, _)| t < j);
+                let prev = if k == 0 { None } else { Some(tails[k - 1].1) };
+                nodes.push((i, prev));
+                let id = nodes.len() - 1;
+                if k == tails.len() {
+                    tails.push((j, id));
+                } else {
+                    tails[k] = (j, id);
+                }
+            }
         }
     }
-    left
+    let mut res = Vec::with_capacity(tails.len());
+    let mut cur = tails.last().map(|&(_, id)| id);
+    while let Some(id) = cur {
+        res.push(a[nodes[id].0].clone());
+        cur = nodes[id].1;
+    }
+    res.reverse();
+    res
 }
 
-/// スライス `v` に対して、要素 `x` を右側（最初に `x` より大きくなる位置）に挿入するためのインデックスを返す。
-/// つまり、`v[..i]` は全て `x` 以下で、`v[i..]` は `x` より大きい最小の `i` を返す。
-pub fn bisect_right<T: Ord>(v: &[T], x: &T) -> i32 {
-    let mut left = 0i32;
-    let mut right = v.len() as i32 - 1;
-    while left <= right {
-        let mid = (left + right) / 2;
-        if v[mid as usize] <= *x {
-            left = mid + 1;
+/// 最長増加部分列
+///
+/// `strict` が `true` なら狭義単調増加、`false` なら広義単調増加の最長部分列を求め、
+/// その添字の列の一例を返す（長さが最適値）。計算量は O(N log N)。
+///
+/// # 例
+///
+/// ```
+/// let a = vec![3, 1, 4, 1, 5, 9, 2, 6];
+/// let idx = lis(&a, true);
+/// assert_eq!(idx.len(), 4); // 例: 1, 4, 5, 9
+/// assert_eq!(lis(&vec![2, 2, 1, 2], false).len(), 3);
+/// ```
+fn lis<T: Ord>(a: &[T], strict: bool) -> Vec<usize> {
+    let mut tails: Vec<usize> = Vec::new();
+    let mut prev = vec![None; a.len()];
+    for (i, x) in a.iter().enumerate() {
+        let k = if strict {
+            tails.partition_point(|&t| a[t] < *x)
+        } else {
+            tails.partition_point(|&t| a[t] <= *x)
+        };
+        if k > 0 {
+            prev[i] = Some(tails[k - 1]);
+        }
+        if k == tails.len() {
+            tails.push(i);
         } else {
-            right = mid - 1;
+            tails[k] = i;
         }
     }
-    left
+    let mut res = Vec::with_capacity(tails.len());
+    let mut cur = tails.last().copied();
+    while let Some(i) = cur {
+        res.push(i);
+        cur = prev[i];
+    }
+    res.reverse();
+    res
 }
 
 fn main() {